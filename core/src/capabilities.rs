@@ -0,0 +1,120 @@
+use serde::Serialize;
+use crate::settings::ApiProvider;
+
+/// An order search results can come back in. Each provider supports its own subset, see [`Capabilities`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SortField {
+    Relevance,
+    Popularity,
+    Featured,
+    LastUpdated,
+    Rating,
+    Created,
+    Downloads,
+    Name,
+}
+
+impl SortField {
+    pub const ALL: [SortField; 8] = [
+        SortField::Relevance,
+        SortField::Popularity,
+        SortField::Featured,
+        SortField::LastUpdated,
+        SortField::Rating,
+        SortField::Created,
+        SortField::Downloads,
+        SortField::Name,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortField::Relevance => "Relevance",
+            SortField::Popularity => "Popularity",
+            SortField::Featured => "Featured",
+            SortField::LastUpdated => "Last Updated",
+            SortField::Rating => "Rating",
+            SortField::Created => "Creation Date",
+            SortField::Downloads => "Total Downloads",
+            SortField::Name => "Name",
+        }
+    }
+
+    /// Name used on the command line, e.g. `last-updated`.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            SortField::Relevance => "relevance",
+            SortField::Popularity => "popularity",
+            SortField::Featured => "featured",
+            SortField::LastUpdated => "last-updated",
+            SortField::Rating => "rating",
+            SortField::Created => "created",
+            SortField::Downloads => "downloads",
+            SortField::Name => "name",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.slug() == slug)
+    }
+}
+
+/// What a provider's search can do. Front ends build their search controls from this instead of
+/// assuming every option exists everywhere; `search_mods_unified` refuses anything not listed here.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Capabilities {
+    /// Supported orders, the first being the default.
+    pub sort_fields: &'static [SortField],
+    /// Whether results can be narrowed by category (CurseForge) or tag (Modtale).
+    pub category_filter: bool,
+    /// How many categories one search may filter by; `None` when there is no limit.
+    pub max_categories: Option<usize>,
+    /// Results per page the provider accepts, the first being the default.
+    pub page_sizes: &'static [u32],
+    /// Whether the provider curates a featured list (`get_featured_unified`).
+    pub featured: bool,
+}
+
+impl Capabilities {
+    pub fn default_sort(&self) -> SortField {
+        self.sort_fields[0]
+    }
+
+    pub fn default_page_size(&self) -> u32 {
+        self.page_sizes[0]
+    }
+
+    pub fn supports_sort(&self, sort: SortField) -> bool {
+        self.sort_fields.contains(&sort)
+    }
+}
+
+const CURSEFORGE: Capabilities = Capabilities {
+    sort_fields: &[
+        SortField::Popularity,
+        SortField::Featured,
+        SortField::LastUpdated,
+        SortField::Rating,
+        SortField::Created,
+        SortField::Downloads,
+        SortField::Name,
+    ],
+    category_filter: true,
+    max_categories: Some(crate::curse_forge_api::MAX_CATEGORY_IDS),
+    page_sizes: &[20, 50],
+    featured: true,
+};
+
+const MODTALE: Capabilities = Capabilities {
+    sort_fields: &[SortField::Relevance, SortField::Downloads, SortField::LastUpdated],
+    category_filter: true,
+    max_categories: None,
+    page_sizes: &[20, 50],
+    featured: false,
+};
+
+pub fn capabilities(provider: &ApiProvider) -> &'static Capabilities {
+    match provider {
+        ApiProvider::CurseForge => &CURSEFORGE,
+        ApiProvider::Modtale => &MODTALE,
+    }
+}
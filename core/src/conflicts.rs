@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::local_mods::{extract_base_name, is_mod_file, read_embedded_manifest, remove_mod};
+use crate::settings::AppSettings;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictKind {
+    /// Several copies of the same version.
+    Duplicate,
+    /// The same mod present in more than one version.
+    VersionMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConflictFile {
+    pub file_name: String,
+    pub version: String,
+    /// Tracked against a provider project. Files that only have a local manifest entry do not count.
+    pub managed: bool,
+    pub modified: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModConflict {
+    pub name: String,
+    pub kind: ConflictKind,
+    pub files: Vec<ConflictFile>,
+}
+
+impl ModConflict {
+    pub fn newest(&self) -> Option<&ConflictFile> {
+        self.files.iter().max_by(|a, b| {
+            compare_versions(&a.version, &b.version).then(a.modified.cmp(&b.modified))
+        })
+    }
+
+    /// The single file installed through the manager, if there is exactly one.
+    pub fn managed(&self) -> Option<&ConflictFile> {
+        let mut managed = self.files.iter().filter(|f| f.managed);
+        match (managed.next(), managed.next()) {
+            (Some(file), None) => Some(file),
+            _ => None,
+        }
+    }
+}
+
+struct ScannedFile {
+    file: ConflictFile,
+    display_name: String,
+    keys: Vec<String>,
+}
+
+/// Groups the files in the Mods folder by provider id, embedded manifest identity or normalized
+/// base name, and reports every group holding more than one file.
+pub fn scan_conflicts(settings: &AppSettings) -> Vec<ModConflict> {
+    let Some(mods_dir) = settings.get_mods_dir() else { return vec![] };
+    let Ok(entries) = fs::read_dir(&mods_dir) else { return vec![] };
+
+    let scanned: Vec<ScannedFile> = entries
+        .flatten()
+        .filter(|e| is_mod_file(&e.path()))
+        .map(|e| scan_file(settings, &e.path(), e.file_name().to_string_lossy().into_owned()))
+        .collect();
+
+    // Union files that share any identity key, so a managed jar and a hand-copied one end up together
+    // even if only one of them is known to the manifest.
+    let mut group_of: Vec<usize> = (0..scanned.len()).collect();
+    let mut owner_of_key: HashMap<&str, usize> = HashMap::new();
+    for (i, file) in scanned.iter().enumerate() {
+        for key in &file.keys {
+            match owner_of_key.get(key.as_str()) {
+                Some(&other) => union(&mut group_of, i, other),
+                None => { owner_of_key.insert(key, i); }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<&ScannedFile>> = HashMap::new();
+    for (i, file) in scanned.iter().enumerate() {
+        let root = find(&mut group_of, i);
+        groups.entry(root).or_default().push(file);
+    }
+
+    let mut conflicts: Vec<ModConflict> = groups
+        .into_values()
+        .filter(|files| files.len() > 1)
+        .map(|files| {
+            let name = files.iter()
+                .find(|f| f.file.managed)
+                .unwrap_or(&files[0])
+                .display_name
+                .clone();
+
+            let first_version = &files[0].file.version;
+            let kind = if files.iter().all(|f| compare_versions(&f.file.version, first_version) == Ordering::Equal) {
+                ConflictKind::Duplicate
+            } else {
+                ConflictKind::VersionMismatch
+            };
+
+            let mut files: Vec<ConflictFile> = files.into_iter().map(|f| f.file.clone()).collect();
+            files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+
+            ModConflict { name, kind, files }
+        })
+        .collect();
+
+    conflicts.sort_by_key(|c| c.name.to_lowercase());
+    conflicts
+}
+
+/// Removes every file of the conflict except `keep`.
+pub fn resolve_conflict(
+    conflict: &ModConflict,
+    keep: &str,
+    settings: &mut AppSettings,
+) -> Result<(), String> {
+    for file in conflict.files.iter().filter(|f| f.file_name != keep) {
+        remove_mod(&file.file_name, settings)?;
+    }
+    Ok(())
+}
+
+fn scan_file(settings: &AppSettings, path: &Path, file_name: String) -> ScannedFile {
+    let (base_name, file_version) = extract_base_name(&file_name);
+    let manifest_entry = settings.get_installed_mod(&file_name);
+    let embedded = read_embedded_manifest(path);
+
+    let mut keys = vec![format!("name:{}", normalize_name(&base_name))];
+    if let Some(entry) = manifest_entry.filter(|e| e.mod_id != "0") {
+        keys.push(format!("id:{:?}:{}", entry.api_provider, entry.mod_id));
+    }
+    if let Some(identity) = embedded.as_ref().and_then(|m| m.identity()) {
+        keys.push(format!("manifest:{}", identity));
+    }
+
+    let version = manifest_entry
+        .map(|e| e.version_name.clone())
+        .or_else(|| embedded.as_ref().and_then(|m| m.version.clone()))
+        .unwrap_or(file_version);
+
+    let display_name = manifest_entry
+        .map(|e| e.mod_name.clone())
+        .or_else(|| embedded.as_ref().and_then(|m| m.name.clone()))
+        .unwrap_or_else(|| base_name.replace('-', " "));
+
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    ScannedFile {
+        file: ConflictFile {
+            file_name,
+            version,
+            managed: manifest_entry.is_some_and(|e| e.mod_id != "0"),
+            modified,
+        },
+        display_name,
+        keys,
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Compares version strings by their numeric components, so `1.10` sorts after `1.9`.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |v: &str| -> Vec<u64> {
+        v.split(|c: char| !c.is_ascii_digit())
+            .filter(|p| !p.is_empty())
+            .filter_map(|p| p.parse().ok())
+            .collect()
+    };
+
+    let (pa, pb) = (parts(a), parts(b));
+    if pa.is_empty() && pb.is_empty() {
+        return a.cmp(b);
+    }
+    pa.cmp(&pb)
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    parent[i] = root;
+    root
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let (ra, rb) = (find(parent, a), find(parent, b));
+    if ra != rb {
+        parent[ra] = rb;
+    }
+}
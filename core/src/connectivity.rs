@@ -0,0 +1,25 @@
+use reqwest::Client;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::curse_forge_api::CURSEFORGE_API;
+use crate::mod_tale_api::MODTALE_API;
+use crate::settings::ApiProvider;
+
+/// How long a probe waits before the provider counts as unreachable.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+static PROBE_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Whether `provider`'s API can be reached at all. Any HTTP answer counts, even an error status: only
+/// failing to connect (no network, DNS failure, timeout) means offline.
+pub async fn is_reachable(provider: &ApiProvider) -> bool {
+    let client = PROBE_CLIENT.get_or_init(|| {
+        Client::builder().timeout(PROBE_TIMEOUT).build().unwrap_or_default()
+    });
+    let base = match provider {
+        ApiProvider::CurseForge => CURSEFORGE_API,
+        ApiProvider::Modtale => MODTALE_API,
+    };
+    client.head(base).send().await.is_ok()
+}
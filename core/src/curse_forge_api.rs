@@ -0,0 +1,812 @@
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use reqwest::{Client, StatusCode};
+use crate::settings::KeyStatus;
+
+pub(crate) const CURSEFORGE_API: &str = "https://api.curseforge.com/v1";
+const HYTALE_GAME_NAME: &str = "Hytale";
+
+static CLIENT: OnceLock<RwLock<Arc<Client>>> = OnceLock::new();
+/// CurseForge's id for Hytale; 0 until `find_hytale_id` looked it up or `set_game_id` restored it.
+static GAME_ID: AtomicU32 = AtomicU32::new(0);
+/// Whether the client carries a non-empty key; without one every request is refused.
+static HAS_KEY: AtomicBool = AtomicBool::new(false);
+static CLASS_NAMES: OnceLock<RwLock<HashMap<u32, String>>> = OnceLock::new();
+static CATEGORIES: OnceLock<RwLock<Vec<Category>>> = OnceLock::new();
+
+fn client_store() -> &'static RwLock<Arc<Client>> {
+    CLIENT.get_or_init(|| RwLock::new(Arc::new(build_client(None))))
+}
+
+fn build_client(api_key: Option<&str>) -> Client {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+    headers.insert(USER_AGENT, HeaderValue::from_static("HytaleModManager/1.0"));
+
+    if let Some(key) = api_key {
+        if let Ok(val) = HeaderValue::from_str(key) {
+            headers.insert("x-api-key", val);
+        }
+    }
+
+    Client::builder()
+        .default_headers(headers)
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+fn client() -> Arc<Client> {
+    client_store().read().unwrap().clone()
+}
+
+
+pub fn set_global_api_key(key: &str) {
+    let new_client = Arc::new(build_client(Some(key)));
+
+    if let Ok(mut lock) = client_store().write() {
+        *lock = new_client;
+        HAS_KEY.store(!key.trim().is_empty(), Ordering::Relaxed);
+        eprintln!("Global API key applied to all future requests on Curse Forge.");
+    }
+}
+
+pub fn has_api_key() -> bool {
+    HAS_KEY.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiFile {
+    pub id: u32,
+    pub display_name: String,
+    pub file_name: String,
+    pub file_date: String,
+    pub file_length: u64,
+    pub release_type: u8,
+    pub download_url: Option<String>,
+    pub game_versions: Vec<String>,
+    #[serde(default)]
+    pub hashes: Vec<FileHash>,
+    #[serde(default)]
+    pub dependencies: Vec<FileDependency>,
+}
+
+
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiResponse<T> {
+    pub data: T,
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination {
+    pub index: u32,
+    pub page_size: u32,
+    pub total_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeMod {
+    pub id: u32,
+    pub game_id: u32,
+    pub name: String,
+    pub slug: String,
+    pub links: ModLinks,
+    pub summary: String,
+    pub status: i32,
+    pub download_count: f64,
+    pub is_featured: bool,
+    pub primary_category_id: Option<u32>,
+    pub categories: Vec<Category>,
+    pub class_id: Option<u32>,
+    pub authors: Vec<Author>,
+    pub logo: Option<ModAsset>,
+    pub screenshots: Vec<ModAsset>,
+    pub main_file_id: u32,
+    pub latest_files: Vec<ModFile>,
+    pub date_created: String,
+    pub date_modified: String,
+    pub date_released: String,
+    pub allow_mod_distribution: Option<bool>,
+    pub game_popularity_rank: Option<u32>,
+    pub is_available: Option<bool>,
+    pub thumbs_up_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModLinks {
+    pub website_url: String,
+    pub wiki_url: Option<String>,
+    pub issues_url: Option<String>,
+    pub source_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    pub id: u32,
+    pub game_id: u32,
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub icon_url: String,
+    pub is_class: Option<bool>,
+    pub class_id: Option<u32>,
+    pub parent_category_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Author {
+    pub id: u32,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModAsset {
+    pub id: u32,
+    pub mod_id: u32,
+    pub title: String,
+    pub description: String,
+    pub thumbnail_url: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModFile {
+    pub id: u32,
+    pub game_id: u32,
+    pub mod_id: u32,
+    pub is_available: bool,
+    pub display_name: String,
+    pub file_name: String,
+    pub release_type: i32,
+    pub file_status: i32,
+    pub hashes: Vec<FileHash>,
+    pub file_date: String,
+    pub file_length: u64,
+    pub download_count: u64,
+    pub download_url: Option<String>,
+    pub game_versions: Vec<String>,
+    pub dependencies: Vec<FileDependency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileHash {
+    pub value: String,
+    pub algo: i32,
+}
+
+/// `FileHash::algo` value for SHA-1; the other one CurseForge uses is 2, MD5.
+pub const HASH_ALGO_SHA1: i32 = 1;
+
+/// The SHA-1 CurseForge lists for a file, lowercase hex.
+pub fn sha1_of(hashes: &[FileHash]) -> Option<String> {
+    hashes.iter()
+        .find(|h| h.algo == HASH_ALGO_SHA1)
+        .map(|h| h.value.to_ascii_lowercase())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDependency {
+    pub mod_id: u32,
+    pub relation_type: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GameData {
+    pub id: u32,
+    pub name: String,
+}
+
+/// `categoryIds` accepts at most this many ids; a mod matches if it is in any of them.
+pub const MAX_CATEGORY_IDS: usize = 10;
+
+pub async fn search_mods(query: String, search_sort: u32, offset: u32, page_size: u32, category_ids: &[u32]) -> Result<(Vec<CurseForgeMod>, Option<Pagination>), String> {
+    let url = format!("{}/mods/search", CURSEFORGE_API);
+    let search_filter = if query.trim().is_empty() { "" } else { &query };
+
+    let mut params = vec![
+        ("gameId", game_id().await?.to_string()),
+        ("searchFilter", search_filter.to_string()),
+        ("pageSize", page_size.to_string()),
+        ("sortField", (search_sort).to_string()),
+        ("sortOrder", "desc".to_string()),
+        ("index", offset.to_string()),
+    ];
+    if !category_ids.is_empty() {
+        let ids = &category_ids[..category_ids.len().min(MAX_CATEGORY_IDS)];
+        params.push(("categoryIds", serde_json::to_string(ids).map_err(|e| e.to_string())?));
+    }
+
+    let resp = client()
+        .get(&url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<CurseForgeMod>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok((json.data, json.pagination))
+}
+
+/// One page of an author's projects, most recently updated first.
+pub async fn search_author_mods(author_id: u32, index: u32, page_size: u32) -> Result<(Vec<CurseForgeMod>, Option<Pagination>), String> {
+    let url = format!("{}/mods/search", CURSEFORGE_API);
+    let params = [
+        ("gameId", game_id().await?.to_string()),
+        ("authorId", author_id.to_string()),
+        ("sortField", "3".to_string()),
+        ("sortOrder", "desc".to_string()),
+        ("index", index.to_string()),
+        ("pageSize", page_size.to_string()),
+    ];
+
+    let resp = client()
+        .get(&url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<CurseForgeMod>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok((json.data, json.pagination))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedMods {
+    pub featured: Vec<CurseForgeMod>,
+    pub popular: Vec<CurseForgeMod>,
+    pub recently_updated: Vec<CurseForgeMod>,
+}
+
+/// CurseForge's curated lists for the game: featured, popular and recently updated.
+pub async fn get_featured() -> Result<FeaturedMods, String> {
+    let url = format!("{}/mods/featured", CURSEFORGE_API);
+    let body = serde_json::json!({ "gameId": game_id().await?, "excludedModIds": [] });
+
+    let resp = client()
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<FeaturedMods> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data)
+}
+
+pub async fn search_exact_mod(query: String) -> Option<CurseForgeMod> {
+    if let Ok((mods, _)) = search_mods(query.clone(), 0, 0, 20, &[]).await {
+        return mods.into_iter().find(|m| {
+            m.name.to_lowercase() == query.to_lowercase() ||
+            m.slug.to_lowercase() == query.to_lowercase()
+        });
+    }
+    None
+}
+
+/// Why `lookup_game_id` failed: the key was refused, or something else went wrong.
+enum LookupError {
+    Rejected(StatusCode),
+    Failed(String),
+}
+
+/// Pages through `/games` until it finds Hytale.
+async fn lookup_game_id(client: &Client) -> Result<u32, LookupError> {
+    let url = format!("{}/games", CURSEFORGE_API);
+    let page_size = 50;
+    let mut index = 0;
+
+    loop {
+        let resp = client
+            .get(&url)
+            .query(&[("index", index), ("pageSize", page_size)])
+            .send()
+            .await
+            .map_err(|e| LookupError::Failed(format!("Network Request Failed: {}", e)))?;
+
+        match resp.status() {
+            status if status.is_success() => {}
+            status @ (StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => return Err(LookupError::Rejected(status)),
+            status => return Err(LookupError::Failed(format!("API Error: {}", status))),
+        }
+
+        let json: ApiResponse<Vec<GameData>> = resp.json()
+            .await
+            .map_err(|e| LookupError::Failed(format!("Failed to parse JSON: {}", e)))?;
+
+        if let Some(hytale) = json.data.iter().find(|g| g.name.eq_ignore_ascii_case(HYTALE_GAME_NAME)) {
+            return Ok(hytale.id);
+        }
+
+        index += page_size;
+        let total = json.pagination.map_or(0, |p| p.total_count);
+        if json.data.is_empty() || index >= total {
+            return Err(LookupError::Failed(format!("{} is not listed on CurseForge", HYTALE_GAME_NAME)));
+        }
+    }
+}
+
+/// Looks Hytale's game id up with the current key and remembers it for the rest of the session.
+pub async fn find_hytale_id() -> Result<u32, String> {
+    let id = lookup_game_id(&client()).await.map_err(|e| match e {
+        LookupError::Rejected(status) => format!("CurseForge rejected the API key ({})", status),
+        LookupError::Failed(e) => e,
+    })?;
+    set_game_id(id);
+    Ok(id)
+}
+
+/// Restores a game id found in an earlier session, so startup needs no extra request.
+pub fn set_game_id(id: u32) {
+    GAME_ID.store(id, Ordering::Relaxed);
+}
+
+pub fn cached_game_id() -> Option<u32> {
+    Some(GAME_ID.load(Ordering::Relaxed)).filter(|id| *id != 0)
+}
+
+async fn game_id() -> Result<u32, String> {
+    match cached_game_id() {
+        Some(id) => Ok(id),
+        None => find_hytale_id().await,
+    }
+}
+
+/// CurseForge keys are bcrypt-style strings: `$2a$10$` followed by 53 characters.
+fn looks_like_key(key: &str) -> bool {
+    key.len() == 60 && key.starts_with("$2a$10$")
+}
+
+/// Tries `key` against the API without touching the global client. The check is the game id lookup,
+/// so a valid key also leaves the id cached.
+pub async fn check_key(key: &str) -> KeyStatus {
+    let key = key.trim();
+    if key.is_empty() {
+        return KeyStatus::Invalid("CurseForge requires an API key".to_string());
+    }
+    if HeaderValue::from_str(key).is_err() {
+        return KeyStatus::Invalid("The key contains characters that cannot be sent".to_string());
+    }
+
+    match lookup_game_id(&build_client(Some(key))).await {
+        Ok(id) => {
+            set_game_id(id);
+            KeyStatus::Valid
+        }
+        // A freshly generated key is refused the same way a wrong one is, so judge by its shape.
+        Err(LookupError::Rejected(_)) if looks_like_key(key) => KeyStatus::NotYetActive,
+        Err(LookupError::Rejected(status)) => KeyStatus::Invalid(format!("CurseForge rejected the key ({})", status)),
+        Err(LookupError::Failed(e)) => KeyStatus::Unverified(e),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FingerprintMatches {
+    exact_matches: Vec<FingerprintMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FingerprintMatch {
+    id: u32,
+    file: ModFile,
+}
+
+/// CurseForge's file fingerprint: MurmurHash2 (seed 1) over the file with whitespace bytes removed.
+pub fn fingerprint(data: &[u8]) -> u32 {
+    const M: u32 = 0x5bd1e995;
+
+    let bytes: Vec<u8> = data.iter()
+        .copied()
+        .filter(|b| !matches!(b, 9 | 10 | 13 | 32))
+        .collect();
+
+    let mut h: u32 = 1 ^ bytes.len() as u32;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M) ^ k;
+    }
+
+    let tail = chunks.remainder();
+    if tail.len() >= 3 { h ^= (tail[2] as u32) << 16; }
+    if tail.len() >= 2 { h ^= (tail[1] as u32) << 8; }
+    if !tail.is_empty() {
+        h ^= tail[0] as u32;
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^ (h >> 15)
+}
+
+/// Looks a file up by its fingerprint. Returns the owning mod id and the matching file, if CurseForge knows it.
+pub async fn match_fingerprint(fingerprint: u32) -> Result<Option<(u32, ModFile)>, String> {
+    let url = format!("{}/fingerprints/{}", CURSEFORGE_API, game_id().await?);
+    let body = serde_json::json!({ "fingerprints": [fingerprint] });
+
+    let resp = client()
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<FingerprintMatches> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data.exact_matches.into_iter().next().map(|m| (m.id, m.file)))
+}
+
+fn class_store() -> &'static RwLock<HashMap<u32, String>> {
+    CLASS_NAMES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Fetches the game's classes (Mods, Worlds, ...) once, so `class_name` can resolve a mod's `class_id`.
+pub async fn load_classes() -> Result<(), String> {
+    if !class_store().read().unwrap().is_empty() {
+        return Ok(());
+    }
+
+    let url = format!("{}/categories", CURSEFORGE_API);
+    let params = [("gameId", game_id().await?.to_string()), ("classesOnly", "true".to_string())];
+
+    let resp = client()
+        .get(&url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<Category>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let mut store = class_store().write().unwrap();
+    for class in json.data {
+        store.insert(class.id, class.name);
+    }
+    Ok(())
+}
+
+pub fn class_name(class_id: u32) -> Option<String> {
+    class_store().read().unwrap().get(&class_id).cloned()
+}
+
+fn category_store() -> &'static RwLock<Vec<Category>> {
+    CATEGORIES.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// The game's whole category tree, fetched once per session. Classes (Mods, Worlds, ...) are the roots;
+/// every other category points at its class through `class_id` and at its parent through `parent_category_id`.
+pub async fn get_categories() -> Result<Vec<Category>, String> {
+    {
+        let cached = category_store().read().unwrap();
+        if !cached.is_empty() {
+            return Ok(cached.clone());
+        }
+    }
+
+    let url = format!("{}/categories", CURSEFORGE_API);
+    let params = [("gameId", game_id().await?.to_string())];
+
+    let resp = client()
+        .get(&url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<Category>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    *category_store().write().unwrap() = json.data.clone();
+    Ok(json.data)
+}
+
+/// Largest `pageSize` the files endpoint accepts.
+pub const MAX_FILES_PAGE_SIZE: u32 = 50;
+
+/// One page of a mod's files, newest first, starting at `index`. `game_version` is matched by the server.
+pub async fn get_mod_files(mod_id: u32, game_version: Option<&str>, index: u32, page_size: u32) -> Result<(Vec<ApiFile>, Option<Pagination>), String> {
+    let url = format!("{}/mods/{}/files", CURSEFORGE_API, mod_id);
+
+    let mut params = vec![
+        ("index", index.to_string()),
+        ("pageSize", page_size.min(MAX_FILES_PAGE_SIZE).to_string()),
+    ];
+    if let Some(game_version) = game_version {
+        params.push(("gameVersion", game_version.to_string()));
+    }
+
+    let resp = client()
+        .get(&url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<ApiFile>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok((json.data, json.pagination))
+}
+
+/// A single file of a mod, however old.
+pub async fn get_mod_file(mod_id: u32, file_id: u32) -> Result<ApiFile, String> {
+    let url = format!("{}/mods/{}/files/{}", CURSEFORGE_API, mod_id, file_id);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    match resp.status() {
+        status if status.is_success() => {}
+        StatusCode::NOT_FOUND => return Err(format!("File {} of mod {} does not exist", file_id, mod_id)),
+        status => return Err(format!("API Error: {}", status)),
+    }
+
+    let json: ApiResponse<ApiFile> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data)
+}
+
+/// The mod's full description, as HTML.
+pub async fn get_mod_description(mod_id: u32) -> Result<String, String> {
+    get_text(&format!("{}/mods/{}/description", CURSEFORGE_API, mod_id)).await
+}
+
+/// What changed in one file, as HTML.
+pub async fn get_file_changelog(mod_id: u32, file_id: u32) -> Result<String, String> {
+    get_text(&format!("{}/mods/{}/files/{}/changelog", CURSEFORGE_API, mod_id, file_id)).await
+}
+
+async fn get_text(url: &str) -> Result<String, String> {
+    let resp = client()
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<String> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data)
+}
+
+#[derive(Debug, Deserialize)]
+struct GameVersionType {
+    versions: Vec<String>,
+}
+
+static GAME_VERSIONS: OnceLock<RwLock<Vec<String>>> = OnceLock::new();
+
+/// Every game version files can be tagged with, fetched once per session.
+pub async fn get_game_versions() -> Result<Vec<String>, String> {
+    let store = GAME_VERSIONS.get_or_init(|| RwLock::new(Vec::new()));
+    {
+        let cached = store.read().unwrap();
+        if !cached.is_empty() {
+            return Ok(cached.clone());
+        }
+    }
+
+    let url = format!("{}/games/{}/versions", CURSEFORGE_API, game_id().await?);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<GameVersionType>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let mut versions: Vec<String> = Vec::new();
+    for version in json.data.into_iter().flat_map(|t| t.versions) {
+        if !versions.contains(&version) {
+            versions.push(version);
+        }
+    }
+
+    *store.write().unwrap() = versions.clone();
+    Ok(versions)
+}
+
+pub async fn download_url(url: &str) -> Result<Vec<u8>, String> {
+    let resp = client()
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| {
+            let err = format!("Request Send Error: {}", e);
+            eprintln!("{}", err);
+            err
+        })?;
+
+    let status = resp.status();
+
+    if !status.is_success() {
+        let err = format!("Download failed: {}", status);
+        eprintln!("{}", err);
+        return Err(err);
+    }
+
+    let bytes = resp.bytes().await.map_err(|e| {
+        let err = format!("Failed to read bytes: {}", e);
+        eprintln!("{}", err);
+        err
+    })?;
+
+    Ok(bytes.to_vec())
+}
+
+pub async fn download_mod(mod_data: &CurseForgeMod) -> Result<(String, Vec<u8>), String> {
+
+    let latest_file = mod_data.latest_files.first()
+        .ok_or_else(|| {
+            let err = "No files found for this mod".to_string();
+            eprintln!("ERROR: {}", err);
+            err
+        })?;
+
+    download_mod_version(latest_file).await
+}
+
+pub async fn download_mod_version(file: &ModFile) -> Result<(String, Vec<u8>), String> {
+    let url = file.download_url.as_ref()
+        .ok_or("No download URL available for this file".to_string())?;
+
+    let bytes = download_url(url).await?;
+
+    Ok((file.file_name.clone(), bytes))
+}
+
+/// The download URL of one specific file. `None` when the author disallows third-party downloads.
+pub async fn get_file_download_url(mod_id: u32, file_id: u32) -> Result<Option<String>, String> {
+    let url = format!("{}/mods/{}/files/{}/download-url", CURSEFORGE_API, mod_id, file_id);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    match resp.status() {
+        status if status.is_success() => {}
+        StatusCode::FORBIDDEN => return Ok(None),
+        StatusCode::NOT_FOUND => return Err(format!("File {} of mod {} does not exist", file_id, mod_id)),
+        status => return Err(format!("API Error: {}", status)),
+    }
+
+    let json: ApiResponse<Option<String>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data.filter(|url| !url.is_empty()))
+}
+
+/// Several mods in one request, e.g. a file's dependencies. Unknown ids are left out of the result.
+pub async fn get_mods(mod_ids: &[u32]) -> Result<Vec<CurseForgeMod>, String> {
+    if mod_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let url = format!("{}/mods", CURSEFORGE_API);
+    let body = serde_json::json!({ "modIds": mod_ids });
+
+    let resp = client()
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("API Error: {}", resp.status()));
+    }
+
+    let json: ApiResponse<Vec<CurseForgeMod>> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(json.data)
+}
+
+pub async fn get_mod(mod_id: u32) -> Result<CurseForgeMod, String> {
+    let url = format!("{}/mods/{}", CURSEFORGE_API, mod_id);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    let status = resp.status();
+    let text = resp.text().await.map_err(|e| format!("Failed to get text: {}", e))?;
+
+    if !status.is_success() {
+        return Err(format!("API Error {}: {}", status, text));
+    }
+
+    let json: ApiResponse<CurseForgeMod> = serde_json::from_str(&text)
+        .map_err(|e| format!("Serde Parsing Error: {} | Raw JSON: {}", e, text))?;
+
+    Ok(json.data)
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use reqwest::Url;
+use crate::settings::ApiProvider;
+
+pub const SCHEME: &str = "hytalemm";
+
+/// What a `hytalemm://` link asks the manager to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLink {
+    /// `hytalemm://install/<provider>/<modId>[/<fileId>]`
+    Install {
+        provider: ApiProvider,
+        mod_id: String,
+        file_id: Option<String>,
+    },
+    /// `hytalemm://import?pack=<url>`
+    ImportPack { url: String },
+}
+
+pub fn is_deep_link(arg: &str) -> bool {
+    arg.to_lowercase().starts_with(&format!("{}://", SCHEME))
+}
+
+pub fn parse_deep_link(link: &str) -> Result<DeepLink, String> {
+    let url = Url::parse(link.trim()).map_err(|_| format!("Not a valid link: {}", link))?;
+    if url.scheme() != SCHEME {
+        return Err(format!("Not a {}:// link", SCHEME));
+    }
+
+    let segments: Vec<&str> = url.path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+
+    match url.host_str() {
+        Some("install") => {
+            let provider = match segments.first().map(|s| s.to_lowercase()).as_deref() {
+                Some("curseforge") => ApiProvider::CurseForge,
+                Some("modtale") => ApiProvider::Modtale,
+                _ => return Err("Install links must name a provider (curseforge or modtale)".to_string()),
+            };
+            let mod_id = segments.get(1)
+                .ok_or("Install link is missing the mod id")?
+                .to_string();
+            if provider == ApiProvider::CurseForge && mod_id.parse::<u32>().is_err() {
+                return Err(format!("Invalid CurseForge mod id: {}", mod_id));
+            }
+
+            Ok(DeepLink::Install {
+                provider,
+                mod_id,
+                file_id: segments.get(2).map(|s| s.to_string()),
+            })
+        }
+        Some("import") => {
+            let pack = url.query_pairs()
+                .find(|(key, _)| key == "pack")
+                .map(|(_, value)| value.into_owned())
+                .ok_or("Import link is missing ?pack=<url>")?;
+            Ok(DeepLink::ImportPack { url: pack })
+        }
+        _ => Err(format!("Unknown link: {}", link)),
+    }
+}
@@ -0,0 +1,134 @@
+use reqwest::header::{CONTENT_DISPOSITION, USER_AGENT};
+use std::fs;
+use std::path::Path;
+use crate::curse_forge_api;
+use crate::local_mods::{embedded_manifest_from_bytes, extract_base_name};
+use crate::manual_download::sha1_hex;
+use crate::settings::{ApiProvider, ContentType, InstalledModEntry};
+use crate::ui_mod::{search_exact_mod_unified, ModMetadata, UiMod};
+
+pub fn read_local_file(path: &Path) -> Result<(String, Vec<u8>), String> {
+    let file_name = path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or("Not a file")?;
+    let data = fs::read(path).map_err(|e| format!("Failed to read {}: {}", file_name, e))?;
+    Ok((file_name, data))
+}
+
+/// Downloads a file from an arbitrary URL (build servers, Discord attachments, ...).
+/// Uses a plain client so provider API keys are never sent to third-party hosts.
+pub async fn download_from_url(url: &str) -> Result<(String, Vec<u8>), String> {
+    let parsed = reqwest::Url::parse(url.trim()).map_err(|_| "Enter a valid http(s) URL".to_string())?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err("Enter a valid http(s) URL".to_string());
+    }
+
+    let resp = reqwest::Client::new()
+        .get(parsed)
+        .header(USER_AGENT, "HytaleModManager/1.0")
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("Download failed: {}", resp.status()));
+    }
+
+    let file_name = resp.headers()
+        .get(CONTENT_DISPOSITION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(file_name_from_disposition)
+        .or_else(|| {
+            resp.url().path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        })
+        .ok_or("Could not tell the file name from the URL")?;
+
+    let bytes = resp.bytes().await.map_err(|e| format!("Failed to read bytes: {}", e))?;
+    Ok((file_name, bytes.to_vec()))
+}
+
+fn file_name_from_disposition(header: &str) -> Option<String> {
+    header.split(';')
+        .map(|part| part.trim())
+        .find_map(|part| part.strip_prefix("filename="))
+        .map(|name| name.trim_matches('"').to_string())
+        .and_then(|name| Path::new(&name).file_name().map(|n| n.to_string_lossy().into_owned()))
+        .filter(|name| !name.is_empty())
+}
+
+/// Manifest entry for a file that did not come from a provider.
+pub fn local_entry(file_name: &str, data: &[u8], content_type: ContentType, provider: &ApiProvider) -> InstalledModEntry {
+    let (base_name, file_version) = extract_base_name(file_name);
+    let embedded = embedded_manifest_from_bytes(data);
+
+    InstalledModEntry {
+        mod_id: "0".to_string(),
+        mod_name: embedded.as_ref()
+            .and_then(|m| m.name.clone())
+            .unwrap_or_else(|| base_name.replace("-", " ")),
+        file_id: String::new(),
+        version_name: embedded.and_then(|m| m.version).unwrap_or(file_version),
+        api_provider: provider.clone(),
+        content_type,
+        metadata: None,
+    }
+}
+
+/// Tries to match a file to a provider project: by CurseForge fingerprint first when a CurseForge key is
+/// set, then by the name in its embedded manifest on `provider`. A name alone can point at someone else's
+/// project, so that match is only taken when the project's latest file has the same SHA-1 as this one.
+/// Falls back to an unidentified local entry.
+pub async fn identify_file(file_name: &str, data: &[u8], content_type: ContentType, provider: &ApiProvider) -> InstalledModEntry {
+    if curse_forge_api::has_api_key() {
+        if let Some(entry) = identify_by_fingerprint(data).await {
+            return entry;
+        }
+    }
+
+    let local = local_entry(file_name, data, content_type, provider);
+    let Some(name) = embedded_manifest_from_bytes(data).and_then(|m| m.name) else {
+        return local;
+    };
+
+    match search_exact_mod_unified(provider, name).await {
+        Ok(ui_mod) if ui_mod.version.sha1.as_deref() == Some(sha1_hex(data).as_str()) => InstalledModEntry {
+            metadata: Some(ModMetadata::from_mod(&ui_mod)),
+            mod_id: ui_mod.id,
+            mod_name: ui_mod.name,
+            file_id: ui_mod.version.file_id,
+            version_name: ui_mod.version.display_name,
+            content_type: ui_mod.content_type,
+            ..local
+        },
+        Ok(ui_mod) => {
+            eprintln!("{} is named like {} ({}) but is not its latest file; keeping it as a local file", file_name, ui_mod.name, ui_mod.id);
+            local
+        }
+        Err(_) => local,
+    }
+}
+
+async fn identify_by_fingerprint(data: &[u8]) -> Option<InstalledModEntry> {
+    let (mod_id, file) = match curse_forge_api::match_fingerprint(curse_forge_api::fingerprint(data)).await {
+        Ok(found) => found?,
+        Err(e) => {
+            eprintln!("Fingerprint lookup FAILED: {}", e);
+            return None;
+        }
+    };
+    let _ = curse_forge_api::load_classes().await;
+    let cf_mod = curse_forge_api::get_mod(mod_id).await.ok()?;
+    let ui_mod = UiMod::from_curseforge_api(&cf_mod);
+    Some(InstalledModEntry {
+        mod_id: mod_id.to_string(),
+        mod_name: ui_mod.name.clone(),
+        file_id: file.id.to_string(),
+        version_name: file.display_name,
+        api_provider: ApiProvider::CurseForge,
+        content_type: ui_mod.content_type,
+        metadata: Some(ModMetadata::from_mod(&ui_mod)),
+    })
+}
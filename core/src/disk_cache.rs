@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::persist::write_atomic;
+use crate::settings::ApiProvider;
+
+const INDEX_FILE: &str = "index.json";
+
+/// How long last-access times may stay unsaved. They only order evictions, so losing some is harmless.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Every cache's index, loaded from disk on first use and kept in memory after that, keyed by cache folder.
+static INDEXES: LazyLock<Mutex<HashMap<PathBuf, Index>>> = LazyLock::new(Default::default);
+
+struct Index {
+    entries: HashMap<String, CacheEntry>,
+    /// Last-access times changed since the index was last written.
+    dirty: bool,
+    written_at: Instant,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CacheEntry {
+    pub key: String,
+    pub file_name: String,
+    pub size: u64,
+    pub last_access: u64,
+}
+
+/// A content store on disk with a size limit. Entries are evicted least-recently-used first.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    limit_bytes: u64,
+}
+
+impl DiskCache {
+    pub fn new(dir: PathBuf, limit_mb: u64) -> Self {
+        Self {
+            dir,
+            limit_bytes: limit_mb * 1024 * 1024,
+        }
+    }
+
+    /// The shared cache every mod install goes through.
+    pub fn downloads(limit_mb: u64) -> Self {
+        Self::new(cache_root().join("downloads"), limit_mb)
+    }
+
+    /// Downloaded mod icons and gallery images, already scaled to the size they are shown at.
+    pub fn images(limit_mb: u64) -> Self {
+        Self::new(cache_root().join("images"), limit_mb)
+    }
+
+    /// A hit only touches the in-memory index; the new access time is written out with the next change
+    /// or once `FLUSH_INTERVAL` has passed. The blob is read without holding the index lock.
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let size = self.with_index(|index| {
+            if !index.entries.contains_key(key) {
+                // Another process (e.g. the CLI) may have added it since the index was loaded.
+                self.reload(index);
+            }
+            index.entries.get(key).map(|e| e.size)
+        })?;
+
+        match fs::read(self.dir.join(blob_name(key))) {
+            Ok(data) if data.len() as u64 == size => {
+                self.with_index(|index| {
+                    if let Some(entry) = index.entries.get_mut(key) {
+                        entry.last_access = now();
+                        index.dirty = true;
+                    }
+                    if index.dirty && index.written_at.elapsed() >= FLUSH_INTERVAL {
+                        self.write_index(index);
+                    }
+                });
+                Some(data)
+            }
+            _ => {
+                self.remove(key);
+                None
+            }
+        }
+    }
+
+    pub fn put(&self, key: &str, file_name: &str, data: &[u8]) -> Result<(), String> {
+        if data.len() as u64 > self.limit_bytes {
+            return Ok(());
+        }
+
+        self.with_index(|index| {
+            fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+            fs::write(self.dir.join(blob_name(key)), data).map_err(|e| e.to_string())?;
+
+            self.reload(index);
+            index.entries.insert(key.to_string(), CacheEntry {
+                key: key.to_string(),
+                file_name: file_name.to_string(),
+                size: data.len() as u64,
+                last_access: now(),
+            });
+
+            self.evict(&mut index.entries, self.limit_bytes);
+            self.write_index(index);
+            Ok(())
+        })
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.with_index(|index| {
+            self.reload(index);
+            index.entries.remove(key);
+            let _ = fs::remove_file(self.dir.join(blob_name(key)));
+            self.write_index(index);
+        });
+    }
+
+    pub fn clear(&self) -> Result<(), String> {
+        self.with_index(|index| {
+            index.entries.clear();
+            index.dirty = false;
+            if self.dir.exists() {
+                fs::remove_dir_all(&self.dir).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Drops entries until the cache fits in its limit again, e.g. after the limit was lowered.
+    pub fn enforce_limit(&self) {
+        self.with_index(|index| {
+            self.reload(index);
+            self.evict(&mut index.entries, self.limit_bytes);
+            self.write_index(index);
+        });
+    }
+
+    /// Entries ordered from most to least recently used.
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        let mut entries: Vec<CacheEntry> = self.with_index(|index| {
+            self.reload(index);
+            index.entries.values().cloned().collect()
+        });
+        entries.sort_by_key(|e| std::cmp::Reverse(e.last_access));
+        entries
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.entries().iter().map(|e| e.size).sum()
+    }
+
+    pub fn limit_bytes(&self) -> u64 {
+        self.limit_bytes
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    fn evict(&self, index: &mut HashMap<String, CacheEntry>, limit: u64) {
+        let mut total: u64 = index.values().map(|e| e.size).sum();
+        if total <= limit {
+            return;
+        }
+
+        let mut by_age: Vec<(String, u64, u64)> = index.values()
+            .map(|e| (e.key.clone(), e.last_access, e.size))
+            .collect();
+        by_age.sort_by_key(|(_, last_access, _)| *last_access);
+
+        for (key, _, size) in by_age {
+            if total <= limit {
+                break;
+            }
+            index.remove(&key);
+            let _ = fs::remove_file(self.dir.join(blob_name(&key)));
+            total = total.saturating_sub(size);
+            eprintln!("[Cache] Evicted {}", key);
+        }
+    }
+
+    /// Runs `f` on this cache's index, loading it from disk the first time.
+    fn with_index<R>(&self, f: impl FnOnce(&mut Index) -> R) -> R {
+        let mut indexes = INDEXES.lock().unwrap();
+        let index = indexes.entry(self.dir.clone()).or_insert_with(|| Index {
+            entries: self.read_index(),
+            dirty: false,
+            written_at: Instant::now(),
+        });
+        f(index)
+    }
+
+    /// Picks up entries other processes added, keeping the newer access time of entries known to both.
+    /// Entries they removed are noticed on the next `get`, which finds the blob gone.
+    fn reload(&self, index: &mut Index) {
+        for (key, entry) in self.read_index() {
+            match index.entries.get_mut(&key) {
+                Some(known) => known.last_access = known.last_access.max(entry.last_access),
+                None => {
+                    index.entries.insert(key, entry);
+                }
+            }
+        }
+    }
+
+    fn read_index(&self) -> HashMap<String, CacheEntry> {
+        fs::read_to_string(self.dir.join(INDEX_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn write_index(&self, index: &mut Index) {
+        index.dirty = false;
+        index.written_at = Instant::now();
+        if let Ok(json) = serde_json::to_string(&index.entries) {
+            if let Err(e) = write_atomic(&self.dir.join(INDEX_FILE), json.as_bytes()) {
+                eprintln!("Failed to write cache index: {}", e);
+            }
+        }
+    }
+}
+
+/// Cache key for a provider file. File ids are unique per provider, so the pair identifies the exact bytes.
+pub fn file_key(provider: &ApiProvider, file_id: &str) -> String {
+    format!("{:?}-{}", provider, file_id).to_lowercase()
+}
+
+fn blob_name(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+fn cache_root() -> PathBuf {
+    let mut path = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+    path.push("hytale-mod-manager");
+    path
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::settings::ModsLayout;
+
+const FLATPAK_LAUNCHER_ID: &str = "com.hypixel.HytaleLauncher";
+
+/// Known places the Hytale launcher keeps its game folder on this OS. Nothing is checked here.
+pub fn candidate_folders() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    // %APPDATA% on Windows, ~/Library/Application Support on macOS, ~/.local/share on Linux.
+    if let Some(data_dir) = dirs::data_dir() {
+        candidates.push(data_dir.join("Hytale"));
+    }
+
+    if cfg!(target_os = "linux") {
+        if let Some(home) = dirs::home_dir() {
+            candidates.push(home.join(".var/app").join(FLATPAK_LAUNCHER_ID).join("data/Hytale"));
+
+            let mut prefixes = vec![home.join(".wine")];
+            prefixes.extend(subdirs(&home.join(".local/share/bottles/bottles")));
+            prefixes.extend(subdirs(&home.join("Games")));
+
+            for prefix in prefixes {
+                for user in subdirs(&prefix.join("drive_c/users")) {
+                    candidates.push(user.join("AppData/Roaming/Hytale"));
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Candidate folders that actually contain a Hytale install, without duplicates.
+pub fn detect_installations() -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = Vec::new();
+    for candidate in candidate_folders() {
+        if looks_like_hytale(&candidate) {
+            let canonical = fs::canonicalize(&candidate).unwrap_or(candidate);
+            if !found.contains(&canonical) {
+                found.push(canonical);
+            }
+        }
+    }
+    found
+}
+
+pub fn looks_like_hytale(path: &Path) -> bool {
+    path.join("UserData").is_dir()
+}
+
+/// Checks a folder picked by the user against the layout it will be used with.
+pub fn validate_root(path: &Path, layout: &ModsLayout) -> Result<(), String> {
+    if !path.is_dir() {
+        return Err("The selected folder does not exist".to_string());
+    }
+
+    match layout {
+        ModsLayout::Client if !looks_like_hytale(path) => {
+            if path.file_name().is_some_and(|n| n == "UserData") {
+                Err("Select the folder that contains UserData, not UserData itself".to_string())
+            } else {
+                Err("This does not look like a Hytale folder (no UserData inside)".to_string())
+            }
+        }
+        ModsLayout::Server if !path.join("mods").is_dir() && !path.join("HytaleServer.jar").is_file() => {
+            Err("This does not look like a Hytale server folder (no mods folder or HytaleServer.jar)".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn subdirs(path: &Path) -> Vec<PathBuf> {
+    fs::read_dir(path)
+        .map(|entries| {
+            entries.flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,180 @@
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat};
+use reqwest::header::USER_AGENT;
+use reqwest::Client;
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use crate::disk_cache::DiskCache;
+
+/// The custom protocol the webview loads cached images through.
+pub const IMAGE_SCHEME: &str = "modimg";
+
+pub const DEFAULT_IMAGE_CACHE_MB: u64 = 256;
+
+/// Set by the app from the settings; the protocol handler has no access to them.
+static LIMIT_MB: AtomicU64 = AtomicU64::new(DEFAULT_IMAGE_CACHE_MB);
+
+static IMAGE_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// The size an image is shown at. Everything but `Full` is scaled down once and stored at that size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSize {
+    /// Mod and category icons.
+    Icon,
+    /// Gallery previews.
+    Thumbnail,
+    /// Banners and the selected gallery image, as published.
+    Full,
+}
+
+impl ImageSize {
+    fn slug(self) -> &'static str {
+        match self {
+            ImageSize::Icon => "icon",
+            ImageSize::Thumbnail => "thumb",
+            ImageSize::Full => "full",
+        }
+    }
+
+    fn from_slug(slug: &str) -> Option<Self> {
+        [ImageSize::Icon, ImageSize::Thumbnail, ImageSize::Full].into_iter().find(|s| s.slug() == slug)
+    }
+
+    /// Longest side in pixels, twice the displayed size for high-DPI screens.
+    fn max_side(self) -> Option<u32> {
+        match self {
+            ImageSize::Icon => Some(128),
+            ImageSize::Thumbnail => Some(320),
+            ImageSize::Full => None,
+        }
+    }
+}
+
+pub fn set_limit_mb(limit_mb: u64) {
+    LIMIT_MB.store(limit_mb, Ordering::Relaxed);
+}
+
+pub fn cache() -> DiskCache {
+    DiskCache::images(LIMIT_MB.load(Ordering::Relaxed))
+}
+
+/// Where the webview should load `url` from: the image cache, filled on first use. Anything that is not
+/// a remote image (empty, data: urls, ...) is returned unchanged.
+pub fn image_src(url: &str, size: ImageSize) -> String {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return url.to_string();
+    }
+    let encoded: String = url.bytes().map(|b| format!("{:02x}", b)).collect();
+    // Webviews on Windows only accept custom schemes in the http://<scheme>.<host> form.
+    if cfg!(windows) {
+        format!("http://{}.localhost/{}/{}", IMAGE_SCHEME, size.slug(), encoded)
+    } else {
+        format!("{}://localhost/{}/{}", IMAGE_SCHEME, size.slug(), encoded)
+    }
+}
+
+/// Answers a request for a path built by `image_src`: the image bytes and their mime type.
+pub async fn serve(path: &str) -> Result<(Vec<u8>, &'static str), String> {
+    let (size, encoded) = path.trim_start_matches('/').split_once('/')
+        .ok_or_else(|| format!("Bad image path {}", path))?;
+    let size = ImageSize::from_slug(size).ok_or_else(|| format!("Unknown image size {}", size))?;
+    let url = decode_hex(encoded).ok_or_else(|| format!("Bad image path {}", path))?;
+
+    let data = load(&url, size).await?;
+    let mime = mime_type(&data);
+    Ok((data, mime))
+}
+
+/// The image at `url` scaled to `size`, from the cache or downloaded and stored.
+/// Disk access and scaling run on the blocking pool, so they do not hold up the async threads.
+pub async fn load(url: &str, size: ImageSize) -> Result<Vec<u8>, String> {
+    let cache = cache();
+    let key = cache_key(url, size);
+    let cached = {
+        let (cache, key) = (cache.clone(), key.clone());
+        blocking(move || cache.get(&key)).await?
+    };
+    if let Some(data) = cached {
+        return Ok(data);
+    }
+
+    let original = download_image(url).await?;
+
+    let file_name = url.rsplit('/').next().and_then(|n| n.split('?').next()).unwrap_or(url);
+    let label = format!("{} ({})", file_name, size.slug());
+    let url = url.to_string();
+    blocking(move || {
+        let data = resize(&original, size).unwrap_or(original);
+        if let Err(e) = cache.put(&key, &label, &data) {
+            eprintln!("[Images] Could not cache {}: {}", url, e);
+        }
+        data
+    }).await
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Result<T, String> {
+    tokio::task::spawn_blocking(f).await.map_err(|e| e.to_string())
+}
+
+/// Image hosts get no API key, unlike the provider clients.
+pub async fn download_image(url: &str) -> Result<Vec<u8>, String> {
+    let resp = IMAGE_CLIENT.get_or_init(Client::new)
+        .get(url)
+        .header(USER_AGENT, "HytaleModManager/1.0")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !resp.status().is_success() {
+        return Err(format!("Image download failed: {}", resp.status()));
+    }
+
+    let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+    Ok(bytes.to_vec())
+}
+
+/// Scales the image down to fit `size`. Icons stay PNG to keep transparency; thumbnails become JPEG.
+/// `None` when the image is already small enough or cannot be decoded (e.g. SVG), so the original is kept.
+fn resize(data: &[u8], size: ImageSize) -> Option<Vec<u8>> {
+    let max_side = size.max_side()?;
+    let img = image::load_from_memory(data).ok()?;
+    if img.width() <= max_side && img.height() <= max_side {
+        return None;
+    }
+    let scaled = img.thumbnail(max_side, max_side);
+
+    let mut out = Cursor::new(Vec::new());
+    if size == ImageSize::Icon {
+        scaled.write_to(&mut out, ImageFormat::Png).ok()?;
+    } else {
+        let rgb = DynamicImage::ImageRgb8(scaled.to_rgb8());
+        rgb.write_with_encoder(JpegEncoder::new_with_quality(&mut out, 85)).ok()?;
+    }
+    Some(out.into_inner())
+}
+
+fn mime_type(data: &[u8]) -> &'static str {
+    match image::guess_format(data) {
+        Ok(format) => format.to_mime_type(),
+        Err(_) if data.trim_ascii_start().starts_with(b"<") => "image/svg+xml",
+        Err(_) => "application/octet-stream",
+    }
+}
+
+fn cache_key(url: &str, size: ImageSize) -> String {
+    let hash: String = Sha256::digest(url.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}", size.slug(), hash)
+}
+
+fn decode_hex(encoded: &str) -> Option<String> {
+    if !encoded.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..encoded.len()).step_by(2)
+        .map(|i| u8::from_str_radix(encoded.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
@@ -0,0 +1,79 @@
+use crate::local_mods::install_mod;
+use crate::manual_download::ManualDownload;
+use crate::settings::{ApiProvider, AppSettings, InstalledModEntry};
+use crate::ui_mod::{download_version_unified, get_mod_details_unified, get_mod_version_unified, resolve_download_url_unified, ModMetadata, UiMod, UiModVersion};
+
+/// A mod file that has been fetched and is ready to be written into an instance.
+pub struct DownloadedMod {
+    pub file_name: String,
+    pub bytes: Vec<u8>,
+    pub entry: InstalledModEntry,
+}
+
+impl DownloadedMod {
+    /// Writes the file into `instance`, replacing whatever file of the same mod was there before. Front ends
+    /// pass the instance that was selected when the install started, not when the download finished.
+    pub fn install(self, instance: usize, settings: &mut AppSettings) -> Result<(), String> {
+        install_mod(&self.file_name, &self.bytes, self.entry, instance, settings)
+    }
+}
+
+/// Fetches exactly `version` of `mod_data`. A version without a download URL is resolved by its file id,
+/// never swapped for another file. Only reads the settings, so front ends can keep them unlocked while the
+/// download runs.
+pub async fn download_version(settings: &AppSettings, mod_data: &UiMod, version: &UiModVersion) -> Result<DownloadedMod, String> {
+    let mut version = version.clone();
+    if version.download_url.is_none() {
+        if mod_data.id == "0" {
+            return Err("Cannot update local-only mod".to_string());
+        }
+        if version.file_id.is_empty() || version.file_id == "0" {
+            return Err(format!("No file is recorded for {}; pick a version to install", mod_data.name));
+        }
+        version.download_url = resolve_download_url_unified(&settings.api_provider, &mod_data.id, &version.file_id).await?;
+        if version.download_url.is_none() {
+            let page = ManualDownload::new(mod_data, &version).page_url();
+            return Err(format!("The author of {} does not allow downloads outside the provider's website; download it from {}", mod_data.name, page));
+        }
+    }
+
+    let (file_name, bytes) = download_version_unified(settings, &version).await?;
+
+    Ok(DownloadedMod {
+        file_name,
+        bytes,
+        entry: InstalledModEntry {
+            mod_id: mod_data.id.clone(),
+            mod_name: mod_data.name.clone(),
+            file_id: version.file_id.clone(),
+            version_name: version.display_name.clone(),
+            api_provider: settings.api_provider.clone(),
+            content_type: mod_data.content_type,
+            metadata: Some(ModMetadata::from_mod(mod_data)),
+        },
+    })
+}
+
+/// Downloads and installs in one go, for front ends that own their settings outright.
+pub async fn install_version(settings: &mut AppSettings, mod_data: &UiMod, version: &UiModVersion) -> Result<(), String> {
+    let instance = settings.active_instance;
+    download_version(settings, mod_data, version).await?.install(instance, settings)
+}
+
+/// Looks up one specific file of a mod, however old, e.g. to roll back to it.
+pub async fn find_version(settings: &AppSettings, mod_id: &str, file_id: &str) -> Result<UiModVersion, String> {
+    get_mod_version_unified(settings, mod_id, file_id).await
+        .map_err(|e| format!("File {} not found for mod {}: {}", file_id, mod_id, e))
+}
+
+/// Looks a mod up on `provider` together with the file to install: `file_id` if given, else its latest file.
+pub async fn resolve_mod(settings: &AppSettings, provider: &ApiProvider, mod_id: &str, file_id: Option<&str>) -> Result<(UiMod, UiModVersion), String> {
+    let settings = settings.for_provider(provider);
+    let mod_data = get_mod_details_unified(provider, mod_id).await
+        .ok_or_else(|| format!("Mod {} not found", mod_id))?;
+    let version = match file_id {
+        Some(file_id) => find_version(&settings, mod_id, file_id).await?,
+        None => mod_data.version.clone(),
+    };
+    Ok((mod_data, version))
+}
@@ -0,0 +1,23 @@
+//! Everything the mod manager does that is not UI: provider clients, settings and instances,
+//! the install manifest, downloads and installs. Front ends (the desktop app, the CLI) sit on top.
+
+pub mod curse_forge_api;
+pub mod mod_tale_api;
+pub mod settings;
+pub mod persist;
+pub mod secrets;
+pub mod local_mods;
+pub mod ui_mod;
+pub mod capabilities;
+pub mod connectivity;
+pub mod rich_text;
+pub mod disk_cache;
+pub mod image_cache;
+pub mod mods_watcher;
+pub mod conflicts;
+pub mod game_detect;
+pub mod direct_install;
+pub mod installer;
+pub mod manual_download;
+pub mod deep_link;
+pub mod modpack;
\ No newline at end of file
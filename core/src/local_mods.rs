@@ -0,0 +1,331 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::settings::{AppSettings, ContentType, InstalledModEntry};
+
+pub fn is_mod_file(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|ext| ext == "jar" || ext == "zip")
+}
+
+/// The `manifest.json` Hytale plugins and asset packs carry at the root of their archive.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmbeddedManifest {
+    pub group: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+}
+
+impl EmbeddedManifest {
+    pub fn identity(&self) -> Option<String> {
+        let name = self.name.as_ref()?.trim().to_lowercase();
+        if name.is_empty() {
+            return None;
+        }
+        match &self.group {
+            Some(group) if !group.trim().is_empty() => Some(format!("{}:{}", group.trim().to_lowercase(), name)),
+            _ => Some(name),
+        }
+    }
+}
+
+pub fn read_embedded_manifest(path: &Path) -> Option<EmbeddedManifest> {
+    let file = fs::File::open(path).ok()?;
+    let mut archive = zip::ZipArchive::new(file).ok()?;
+    let entry = archive.by_name("manifest.json").ok()?;
+    serde_json::from_reader(entry).ok()
+}
+
+pub fn embedded_manifest_from_bytes(data: &[u8]) -> Option<EmbeddedManifest> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).ok()?;
+    let entry = archive.by_name("manifest.json").ok()?;
+    serde_json::from_reader(entry).ok()
+}
+
+/// Rejects anything the game would not load: wrong extension or not a readable archive.
+pub fn validate_mod_archive(file_name: &str, data: &[u8], content_type: ContentType) -> Result<(), String> {
+    let extension = Path::new(file_name).extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !content_type.is_extracted() && extension != "jar" && extension != "zip" {
+        return Err(format!("{} is not a .jar or .zip file", file_name));
+    }
+    if !is_zip(data) || zip::ZipArchive::new(std::io::Cursor::new(data)).is_err() {
+        return Err(format!("{} is not a valid archive", file_name));
+    }
+    Ok(())
+}
+
+pub fn extract_base_name(filename: &str) -> (String, String) {
+    let name_without_extension = filename
+        .strip_suffix(".jar")
+        .unwrap_or_else(|| filename.strip_suffix(".zip").unwrap_or(filename));
+
+    let split_index = name_without_extension
+        .char_indices()
+        .find(|(i, c)| {
+            if *c == '-' {
+                if let Some(next_char) = name_without_extension[i + 1..].chars().next() {
+                    return next_char.is_numeric() || next_char == 'v';
+                }
+            }
+            false
+        })
+        .map(|(i, _)| i);
+
+    match split_index {
+        Some(idx) => {
+            let (name, version_with_dash) = name_without_extension.split_at(idx);
+            let version = &version_with_dash[1..];
+            (name.to_string(), version.to_string())
+        }
+        None => (name_without_extension.to_string(), "Unknown".to_string()),
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InstallStatus {
+    Installed,
+    #[default]
+    NotInstalled,
+    Outdated,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModInstallInfo {
+    pub mod_name: String,
+    pub local_version: Option<String>,
+    pub local_file_name: Option<String>,
+    pub install_status: InstallStatus,
+}
+
+impl ModInstallInfo {
+    pub fn from_id(
+        settings: &AppSettings,
+        mod_id: &str,
+        latest_file_id: &str
+    ) -> Self {
+        check_install_status(settings, mod_id, latest_file_id)
+    }
+}
+
+/// File id of the installed copy of a provider mod, if there is one.
+pub fn installed_file_id(settings: &AppSettings, mod_id: &str) -> Option<String> {
+    if mod_id == "0" {
+        return None;
+    }
+    settings.installed_mods().values()
+        .find(|e| e.mod_id == mod_id)
+        .map(|e| e.file_id.clone())
+}
+
+pub fn check_install_status(
+    settings: &AppSettings,
+    mod_id: &str,
+    latest_file_id: &str
+) -> ModInstallInfo {
+    let entry_opt = settings.installed_mods().values().find(|e| e.mod_id == mod_id);
+
+    if let Some(entry) = entry_opt {
+        let status = if entry.file_id == latest_file_id {
+            InstallStatus::Installed
+        } else {
+            InstallStatus::Outdated
+        };
+
+        let file_name = settings.installed_mods().iter()
+            .find(|(_, v)| v.mod_id == mod_id)
+            .map(|(k, _)| k.clone());
+
+        ModInstallInfo {
+            mod_name: entry.mod_name.clone(),
+            local_version: Some(entry.version_name.clone()),
+            local_file_name: file_name,
+            install_status: status,
+        }
+    } else {
+        ModInstallInfo {
+            mod_name: "".to_string(),
+            local_version: None,
+            local_file_name: None,
+            install_status: InstallStatus::NotInstalled,
+        }
+    }
+}
+
+/// Writes a downloaded file to the folder for its content type in `instance` and records it in that
+/// instance's manifest. Worlds and prefab bundles are extracted; any previously installed file of the
+/// same mod is replaced.
+pub fn install_mod(
+    file_name: &str,
+    data: &[u8],
+    entry: InstalledModEntry,
+    instance: usize,
+    settings: &mut AppSettings,
+) -> Result<(), String> {
+    validate_mod_archive(file_name, data, entry.content_type)?;
+
+    let target = settings.instance(instance).ok_or("The instance to install into no longer exists")?;
+    let target_dir = target.content_dir(entry.content_type)
+        .ok_or("No Game Folder Set")?;
+    if !target_dir.exists() {
+        fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
+    }
+
+    let old_key = target.installed_mods.iter()
+        .find(|(k, v)| if entry.mod_id == "0" { k.as_str() == file_name } else { v.mod_id == entry.mod_id })
+        .map(|(k, _)| k.clone());
+
+    // The old copy is moved aside rather than deleted, so it can be put back if the new one cannot be written.
+    let staged = match &old_key {
+        Some(old_key) => {
+            let content_type = target.installed_mods[old_key].content_type;
+            let old_path = target.content_dir(content_type).ok_or("No Game Folder Set")?.join(old_key);
+            stage_aside(&old_path)?.map(|staged| (old_path, staged))
+        }
+        None => None,
+    };
+
+    let written = if entry.content_type.is_extracted() && is_zip(data) {
+        extract_archive(&target_dir, file_name, data)
+    } else {
+        let path = target_dir.join(file_name);
+        fs::write(&path, data).map(|_| file_name.to_string()).map_err(|e| {
+            let _ = fs::remove_file(&path);
+            e.to_string()
+        })
+    };
+
+    let installed_name = match written {
+        Ok(name) => name,
+        Err(e) => {
+            if let Some((old_path, staged)) = staged {
+                if let Err(restore_err) = fs::rename(&staged, &old_path) {
+                    eprintln!("[Install] Could not restore {} from {}: {}", old_path.display(), staged.display(), restore_err);
+                }
+            }
+            return Err(e);
+        }
+    };
+
+    if let Some((_, staged)) = staged {
+        if let Err(e) = remove_path(&staged) {
+            eprintln!("[Install] Could not remove the replaced copy {}: {}", staged.display(), e);
+        }
+    }
+    if let Some(old_key) = old_key {
+        settings.remove_installed_mod(instance, &old_key);
+    }
+    settings.add_installed_mod(instance, installed_name, entry);
+
+    Ok(())
+}
+
+/// Renames `path` to a hidden sibling and returns the new location, or `None` if there was nothing to move.
+fn stage_aside(path: &Path) -> Result<Option<PathBuf>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let staged = path.with_file_name(format!(".{}.replaced", name));
+    if staged.exists() {
+        remove_path(&staged).map_err(|e| e.to_string())?;
+    }
+    fs::rename(path, &staged).map_err(|e| format!("Could not move {} aside: {}", path.display(), e))?;
+    Ok(Some(staged))
+}
+
+fn remove_path(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Deletes an installed file or extracted folder of the selected instance and drops it from the manifest.
+pub fn remove_mod(file_name: &str, settings: &mut AppSettings) -> Result<(), String> {
+    let content_type = settings.get_installed_mod(file_name)
+        .map(|e| e.content_type)
+        .unwrap_or_default();
+    let dir = settings.get_content_dir(content_type).ok_or("No Game Folder Set")?;
+
+    let path = dir.join(file_name);
+    if path.exists() {
+        remove_path(&path).map_err(|e| e.to_string())?;
+    }
+    settings.remove_installed_mod(settings.active_instance, file_name);
+
+    Ok(())
+}
+
+fn is_zip(data: &[u8]) -> bool {
+    data.starts_with(b"PK\x03\x04")
+}
+
+/// Unpacks `data` into a folder inside `target_dir` and returns that folder's name. An archive that
+/// wraps everything in one top-level folder keeps that folder's name; otherwise the archive name is used.
+fn extract_archive(target_dir: &Path, file_name: &str, data: &[u8]) -> Result<String, String> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).map_err(|e| e.to_string())?;
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    let mut has_root_files = false;
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i).map_err(|e| e.to_string())?;
+        let Some(path) = entry.enclosed_name() else { continue };
+        if path.components().count() == 1 && !entry.is_dir() {
+            has_root_files = true;
+        }
+        if let Some(first) = path.components().next() {
+            let first = PathBuf::from(first.as_os_str());
+            if !roots.contains(&first) {
+                roots.push(first);
+            }
+        }
+    }
+
+    let wrapped_root = if !has_root_files && roots.len() == 1 { roots.pop() } else { None };
+    let folder_name = match &wrapped_root {
+        Some(root) => root.to_string_lossy().into_owned(),
+        None => Path::new(file_name)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file_name.to_string()),
+    };
+
+    let destination = target_dir.join(&folder_name);
+    if destination.exists() {
+        return Err(format!("{} already exists in {}", folder_name, target_dir.display()));
+    }
+
+    // A half-extracted folder is worse than none.
+    if let Err(e) = unpack(&mut archive, wrapped_root.as_deref(), &destination) {
+        let _ = fs::remove_dir_all(&destination);
+        return Err(e);
+    }
+
+    Ok(folder_name)
+}
+
+fn unpack(archive: &mut zip::ZipArchive<std::io::Cursor<&[u8]>>, wrapped_root: Option<&Path>, destination: &Path) -> Result<(), String> {
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+        let Some(path) = entry.enclosed_name() else { continue };
+        let relative = match wrapped_root {
+            Some(root) => path.strip_prefix(root).map(Path::to_path_buf).unwrap_or(path),
+            None => path,
+        };
+        let out_path = destination.join(relative);
+
+        if entry.is_dir() {
+            fs::create_dir_all(&out_path).map_err(|e| e.to_string())?;
+        } else {
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let mut out = fs::File::create(&out_path).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
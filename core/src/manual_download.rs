@@ -0,0 +1,144 @@
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::installer::DownloadedMod;
+use crate::settings::{AppSettings, InstalledModEntry};
+use crate::ui_mod::{ModMetadata, UiMod, UiModVersion};
+
+/// Browsers add " (1)", " (2)", ... when the name is taken; this is how far we look.
+const MAX_DUPLICATE_SUFFIX: u32 = 20;
+
+/// A file whose author disabled third-party distribution. The user downloads it from the provider's page
+/// in their browser; we watch the Downloads folder for it, check it is the right file and install it
+/// as if it had been downloaded directly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManualDownload {
+    pub mod_data: UiMod,
+    pub version: UiModVersion,
+}
+
+/// How a file found in Downloads compares to the one expected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Candidate {
+    /// Passed the hash check.
+    Match(PathBuf),
+    /// Name and size fit but there is no hash to check, so the file may still be being written. It counts
+    /// as a match once a later look finds it at the same size.
+    Unconfirmed(PathBuf, u64),
+    /// The name fits but the content does not, e.g. another version of the mod.
+    Mismatch(PathBuf),
+}
+
+impl ManualDownload {
+    pub fn new(mod_data: &UiMod, version: &UiModVersion) -> Self {
+        Self { mod_data: mod_data.clone(), version: version.clone() }
+    }
+
+    /// The provider page the file can be downloaded from.
+    pub fn page_url(&self) -> String {
+        if self.version.file_id.is_empty() || self.mod_data.website_url.is_empty() {
+            return self.mod_data.website_url.clone();
+        }
+        format!("{}/files/{}", self.mod_data.website_url.trim_end_matches('/'), self.version.file_id)
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.version.file_name
+    }
+
+    /// Where browsers save files by default.
+    pub fn downloads_dir() -> Option<PathBuf> {
+        dirs::download_dir()
+    }
+
+    /// Looks for the expected file in `dir`, including copies the browser renamed to avoid a clash.
+    /// A file that matches wins over ones that only share the name. `previous` is what the last look
+    /// found, which confirms a file without a hash once its size has stopped changing.
+    pub fn find_in(&self, dir: &Path, previous: Option<&Candidate>) -> Option<Candidate> {
+        let mut mismatch = None;
+        for path in self.candidate_paths(dir) {
+            if !path.is_file() {
+                continue;
+            }
+            if !self.matches(&path) {
+                mismatch.get_or_insert(path);
+                continue;
+            }
+            if self.version.sha1.is_some() {
+                return Some(Candidate::Match(path));
+            }
+            let size = fs::metadata(&path).map(|m| m.len()).unwrap_or_default();
+            return match previous {
+                Some(Candidate::Unconfirmed(seen, seen_size)) if *seen == path && *seen_size == size => Some(Candidate::Match(path)),
+                _ => Some(Candidate::Unconfirmed(path, size)),
+            };
+        }
+        mismatch.map(Candidate::Mismatch)
+    }
+
+    fn candidate_paths(&self, dir: &Path) -> Vec<PathBuf> {
+        let name = Path::new(self.file_name());
+        let stem = name.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+        let extension = name.extension().and_then(|e| e.to_str());
+
+        let mut paths = vec![dir.join(name)];
+        paths.extend((1..=MAX_DUPLICATE_SUFFIX).map(|n| match extension {
+            Some(ext) => dir.join(format!("{} ({}).{}", stem, n, ext)),
+            None => dir.join(format!("{} ({})", stem, n)),
+        }));
+        paths
+    }
+
+    /// Checks size and SHA-1 against what the provider published. Without a hash only the size is checked.
+    fn matches(&self, path: &Path) -> bool {
+        if let Some(size) = self.version.file_size {
+            if fs::metadata(path).map(|m| m.len()).ok() != Some(size) {
+                return false;
+            }
+        }
+        match &self.version.sha1 {
+            Some(expected) => fs::read(path).map(|data| sha1_hex(&data) == *expected).unwrap_or(false),
+            None => true,
+        }
+    }
+
+    /// Installs the file at `path` into `instance`, registering it in the manifest the same way a direct
+    /// download would be. A file found in Downloads is removed from there; one the user picked elsewhere
+    /// is left where it is.
+    pub fn install_from(&self, path: &Path, instance: usize, settings: &mut AppSettings) -> Result<(), String> {
+        let bytes = fs::read(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        if let Some(expected) = &self.version.sha1 {
+            if sha1_hex(&bytes) != *expected {
+                return Err(format!("{} is not the expected file (hash mismatch)", path.display()));
+            }
+        }
+
+        let downloaded = DownloadedMod {
+            file_name: self.file_name().to_string(),
+            bytes,
+            entry: InstalledModEntry {
+                mod_id: self.mod_data.id.clone(),
+                mod_name: self.mod_data.name.clone(),
+                file_id: self.version.file_id.clone(),
+                version_name: self.version.display_name.clone(),
+                api_provider: settings.api_provider.clone(),
+                content_type: self.mod_data.content_type,
+                metadata: Some(ModMetadata::from_mod(&self.mod_data)),
+            },
+        };
+        downloaded.install(instance, settings)?;
+
+        if path.parent().is_some_and(|dir| Self::downloads_dir().is_some_and(|downloads| downloads == dir)) {
+            if let Err(e) = fs::remove_file(path) {
+                eprintln!("[Manual Download] Installed, but could not remove {}: {}", path.display(), e);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lowercase hex SHA-1 of `data`, as providers publish it.
+pub(crate) fn sha1_hex(data: &[u8]) -> String {
+    Sha1::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -1,10 +1,11 @@
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, OnceLock, RwLock};
-use reqwest::Client;
-use crate::api::curse_forge_api::CurseForgeMod;
+use reqwest::{Client, StatusCode};
+use crate::curse_forge_api::CurseForgeMod;
+use crate::settings::KeyStatus;
 
-const MODTALE_API: &str = "https://api.modtale.net/api/v1";
+pub(crate) const MODTALE_API: &str = "https://api.modtale.net/api/v1";
 const MODTALE_CDN: &str = "https://cdn.modtale.net";
 
 static CLIENT: OnceLock<RwLock<Arc<Client>>> = OnceLock::new();
@@ -39,7 +40,7 @@ pub fn set_global_api_key(key: &str) {
     let new_client = Arc::new(build_client(Some(key)));
     if let Ok(mut lock) = client_store().write() {
         *lock = new_client;
-        println!("Global API key applied to all future requests on ModTale.");
+        eprintln!("Global API key applied to all future requests on ModTale.");
     }
 }
 
@@ -66,6 +67,10 @@ pub struct ModTaleMod {
     #[serde(alias = "description")]
     pub summary: Option<String>,
 
+    /// The full project page, in Markdown.
+    #[serde(default, alias = "about")]
+    pub body: Option<String>,
+
     pub author: String,
 
     #[serde(alias = "imageUrl")]
@@ -78,6 +83,9 @@ pub struct ModTaleMod {
 
     pub categories: Option<Vec<String>>,
 
+    #[serde(default, alias = "projectType")]
+    pub classification: Option<String>,
+
     pub created_at: String,
     pub updated_at: String,
 
@@ -97,6 +105,9 @@ pub struct ModTaleFile {
     pub created_at: String,
     pub download_count: u64,
     pub channel: Option<String>,
+    /// Markdown.
+    #[serde(default)]
+    pub changelog: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -107,20 +118,22 @@ pub struct Meta {
     pub per_page: u32,
 }
 
-pub async fn search_mods(query: String, sort: &str, offset: u32) -> Result<(Vec<ModTaleMod>, Option<Meta>), String> {
+pub async fn search_mods(query: String, sort: &str, offset: u32, limit: u32, tags: &[String]) -> Result<(Vec<ModTaleMod>, Option<Meta>), String> {
     let url = format!("{}/projects", MODTALE_API);
 
-    let limit: u32 = 20;
     let page_index = offset / limit;
 
-    let params = [
-        ("q", query.as_str()),
-        ("sort", sort),
-        ("page", &page_index.to_string()),
-        ("size", &limit.to_string()),
+    let mut params = vec![
+        ("q", query),
+        ("sort", sort.to_string()),
+        ("page", page_index.to_string()),
+        ("size", limit.to_string()),
     ];
+    if !tags.is_empty() {
+        params.push(("tags", tags.join(",")));
+    }
 
-    println!("[ModTale DEBUG] Searching Page: {} (Limit: {})", page_index, limit);
+    eprintln!("[ModTale DEBUG] Searching Page: {} (Limit: {})", page_index, limit);
 
     let resp = client()
         .get(&url)
@@ -147,8 +160,61 @@ pub async fn search_mods(query: String, sort: &str, offset: u32) -> Result<(Vec<
     Ok((json.content, Some(meta)))
 }
 
+/// Tries `key` against the API without touching the global client. Modtale works without a key, so an
+/// empty one is fine.
+pub async fn check_key(key: &str) -> KeyStatus {
+    let key = key.trim();
+    if key.is_empty() {
+        return KeyStatus::Valid;
+    }
+    if HeaderValue::from_str(key).is_err() {
+        return KeyStatus::Invalid("The key contains characters that cannot be sent".to_string());
+    }
+
+    let resp = build_client(Some(key))
+        .get(format!("{}/projects", MODTALE_API))
+        .query(&[("size", "1")])
+        .send()
+        .await;
+
+    match resp {
+        Ok(resp) if resp.status().is_success() => KeyStatus::Valid,
+        Ok(resp) if matches!(resp.status(), StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+            KeyStatus::Invalid(format!("ModTale rejected the key ({})", resp.status()))
+        }
+        Ok(resp) => KeyStatus::Unverified(format!("ModTale API Error: {}", resp.status())),
+        Err(e) => KeyStatus::Unverified(format!("Network Request Failed: {}", e)),
+    }
+}
+
+/// All project tags, fetched once per session.
+pub async fn get_tags() -> Result<Vec<String>, String> {
+    static TAGS: OnceLock<Vec<String>> = OnceLock::new();
+    if let Some(tags) = TAGS.get() {
+        return Ok(tags.clone());
+    }
+
+    let url = format!("{}/tags", MODTALE_API);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if !resp.status().is_success() {
+        return Err(format!("ModTale API Error: {}", resp.status()));
+    }
+
+    let tags: Vec<String> = resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    Ok(TAGS.get_or_init(|| tags).clone())
+}
+
 pub async fn search_exact_mod(query: String) -> Option<ModTaleMod> {
-    if let Ok((mods, _)) = search_mods(query.clone(), "desc", 0).await {
+    if let Ok((mods, _)) = search_mods(query.clone(), "desc", 0, 20, &[]).await {
         return mods.into_iter().find(|m| {
             m.name.to_lowercase() == query.to_lowercase() ||
             m.slug.to_owned().unwrap_or_default().to_lowercase() == query.to_lowercase()
@@ -183,6 +249,28 @@ pub async fn get_mod_files(mod_id: &str) -> Result<Vec<ModTaleFile>, String> {
     Ok(mod_data.versions.unwrap_or_default())
 }
 
+/// One version of a project. Falls back to the project's version list if the version endpoint is unavailable.
+pub async fn get_mod_file(mod_id: &str, version_id: &str) -> Result<ModTaleFile, String> {
+    let url = format!("{}/projects/{}/versions/{}", MODTALE_API, mod_id, version_id);
+
+    let resp = client()
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network Request Failed: {}", e))?;
+
+    if resp.status().is_success() {
+        if let Ok(file) = resp.json::<ModTaleFile>().await {
+            return Ok(file);
+        }
+    }
+
+    get_mod_files(mod_id).await?
+        .into_iter()
+        .find(|f| f.id == version_id)
+        .ok_or_else(|| format!("Version {} of project {} does not exist", version_id, mod_id))
+}
+
 pub async fn download_url(url: &str) -> Result<Vec<u8>, String> {
     let full_url = if url.starts_with("http") {
         url.to_string()
@@ -190,7 +278,7 @@ pub async fn download_url(url: &str) -> Result<Vec<u8>, String> {
         format!("{}/{}", MODTALE_CDN, url)
     };
 
-    println!("[ModTale] Downloading from: {}", full_url);
+    eprintln!("[ModTale] Downloading from: {}", full_url);
 
     let resp = client()
         .get(&full_url)
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use crate::direct_install::download_from_url;
+
+/// The `manifest.json` of a CurseForge-style modpack: a list of project/file pairs.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModPack {
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    pub files: Vec<PackFile>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PackFile {
+    #[serde(rename = "projectID")]
+    pub project_id: u32,
+    #[serde(rename = "fileID")]
+    pub file_id: u32,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+/// Reads a pack from either a bare `manifest.json` or a pack archive containing one.
+pub fn parse_pack(data: &[u8]) -> Result<ModPack, String> {
+    if data.starts_with(b"PK\x03\x04") {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).map_err(|e| e.to_string())?;
+        let manifest = archive.by_name("manifest.json").map_err(|_| "The pack has no manifest.json".to_string())?;
+        serde_json::from_reader(manifest).map_err(|e| format!("Invalid pack manifest: {}", e))
+    } else {
+        serde_json::from_slice(data).map_err(|e| format!("Invalid pack manifest: {}", e))
+    }
+}
+
+pub async fn fetch_pack(url: &str) -> Result<ModPack, String> {
+    let (_, data) = download_from_url(url).await?;
+    parse_pack(&data)
+}
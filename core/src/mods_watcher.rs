@@ -0,0 +1,34 @@
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+/// Watches the Mods folder and signals whenever a file in it is created, removed, renamed or rewritten.
+/// The watcher stops as soon as the returned `RecommendedWatcher` is dropped.
+pub fn watch_mods_dir(mods_dir: &Path) -> Result<(RecommendedWatcher, UnboundedReceiver<()>), String> {
+    watch_dir(mods_dir)
+}
+
+/// Same as [`watch_mods_dir`] for any folder, e.g. Downloads while waiting for a manual download.
+pub fn watch_dir(dir: &Path) -> Result<(RecommendedWatcher, UnboundedReceiver<()>), String> {
+    let (tx, rx) = unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        match res {
+            Ok(event) => {
+                let relevant = matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(_)
+                );
+                if relevant {
+                    let _ = tx.send(());
+                }
+            }
+            Err(e) => eprintln!("[Watcher] Error: {}", e),
+        }
+    }).map_err(|e| e.to_string())?;
+
+    watcher.watch(dir, RecursiveMode::NonRecursive).map_err(|e| e.to_string())?;
+    eprintln!("[Watcher] Watching {:?}", dir);
+
+    Ok((watcher, rx))
+}
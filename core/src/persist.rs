@@ -0,0 +1,268 @@
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Condvar, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How long a scheduled write waits for further changes before it hits the disk.
+const DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// What this process last read from or wrote to each scheduled file: the common ancestor when another
+/// process (e.g. the CLI next to the GUI) changed the file in the meantime.
+static BASES: LazyLock<Mutex<HashMap<PathBuf, Vec<u8>>>> = LazyLock::new(Default::default);
+
+/// Replaces `path` with `data` so that a crash leaves either the old or the new file, never a torn one:
+/// the data goes to a temp file next to it, is fsynced, then renamed over the original.
+/// Other processes using this module are kept out by an advisory lock on `<path>.lock`.
+pub fn write_atomic(path: &Path, data: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let _lock = lock(path, true)?;
+    replace(path, data, false)
+}
+
+/// [`write_atomic`] for files only the current user may read. On Unix the temp file is created with mode
+/// 0600, so the data is never readable by others, not even before the rename.
+pub fn write_private(path: &Path, data: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let _lock = lock(path, true)?;
+    replace(path, data, true)
+}
+
+fn replace(path: &Path, data: &[u8], private: bool) -> io::Result<()> {
+    let tmp = sidecar(path, "tmp");
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        // The mode only applies to new files, so a temp file left by a crash must not be reused.
+        let _ = fs::remove_file(&tmp);
+        options.mode(0o600);
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    let mut file = options.open(&tmp)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    drop(file);
+
+    if let Err(e) = fs::rename(&tmp, path) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+
+    // Persist the rename itself; directories cannot be opened for syncing on Windows.
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        File::open(parent)?.sync_all()?;
+    }
+    Ok(())
+}
+
+/// Reads `path` while holding the shared side of the lock `write_atomic` takes. The content is remembered
+/// as the base that later scheduled writes to `path` are merged against.
+pub fn read_locked(path: &Path) -> io::Result<String> {
+    let _lock = lock(path, false)?;
+    let content = fs::read_to_string(path)?;
+    set_base(path, content.as_bytes().to_vec());
+    Ok(content)
+}
+
+/// Whether `path` differs from what this process last read or wrote, i.e. another process wrote it since.
+pub fn changed_externally(path: &Path) -> bool {
+    let Some(base) = BASES.lock().unwrap_or_else(|e| e.into_inner()).get(path).cloned() else { return false };
+    let Ok(_lock) = lock(path, false) else { return false };
+    fs::read(path).is_ok_and(|data| data != base)
+}
+
+fn set_base(path: &Path, data: Vec<u8>) {
+    BASES.lock().unwrap_or_else(|e| e.into_inner()).insert(path.to_path_buf(), data);
+}
+
+/// Writes a scheduled JSON snapshot. If another process changed the file since we last read or wrote it,
+/// its changes are merged in: values only they changed are kept, values only we changed are ours, and where
+/// both changed the same value ours wins. The lock is held from reading the file until it is replaced.
+fn write_merged(path: &Path, data: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let _lock = lock(path, true)?;
+
+    let base = BASES.lock().unwrap_or_else(|e| e.into_inner()).get(path).cloned();
+    let merged = match (base, fs::read(path).ok()) {
+        (Some(base), Some(theirs)) if theirs != base => merge_json(&base, data, &theirs).or_else(|| {
+            eprintln!("{:?} changed on disk but could not be merged; overwriting it", path);
+            None
+        }),
+        _ => None,
+    };
+    if merged.is_some() {
+        eprintln!("{:?} was changed by another process; merged its changes", path);
+    }
+    replace(path, merged.as_deref().unwrap_or(data), false)?;
+
+    // Our own snapshot, not the merged file: anything we have not seen yet still counts as theirs.
+    set_base(path, data.to_vec());
+    Ok(())
+}
+
+fn merge_json(base: &[u8], ours: &[u8], theirs: &[u8]) -> Option<Vec<u8>> {
+    let base: Value = serde_json::from_slice(base).ok()?;
+    let ours: Value = serde_json::from_slice(ours).ok()?;
+    let theirs: Value = serde_json::from_slice(theirs).ok()?;
+    let merged = merge_values(Some(&base), Some(&ours), Some(&theirs)).unwrap_or(Value::Null);
+    serde_json::to_vec_pretty(&merged).ok()
+}
+
+/// Three-way merge of one value; `None` is a missing object field.
+fn merge_values(base: Option<&Value>, ours: Option<&Value>, theirs: Option<&Value>) -> Option<Value> {
+    if ours == base {
+        return theirs.cloned();
+    }
+    if theirs == base || theirs == ours {
+        return ours.cloned();
+    }
+    match (base, ours, theirs) {
+        (base, Some(Value::Object(ours)), Some(Value::Object(theirs))) => {
+            let empty = Map::new();
+            let base = match base {
+                Some(Value::Object(base)) => base,
+                _ => &empty,
+            };
+            let mut merged = Map::new();
+            for key in ours.keys().chain(theirs.keys().filter(|k| !ours.contains_key(*k))) {
+                if let Some(value) = merge_values(base.get(key), ours.get(key), theirs.get(key)) {
+                    merged.insert(key.clone(), value);
+                }
+            }
+            Some(Value::Object(merged))
+        }
+        (Some(Value::Array(base)), Some(Value::Array(ours)), Some(Value::Array(theirs)))
+            if base.len() == ours.len() && ours.len() == theirs.len() =>
+        {
+            let merged = base.iter().zip(ours).zip(theirs)
+                .map(|((b, o), t)| merge_values(Some(b), Some(o), Some(t)).unwrap_or(Value::Null))
+                .collect();
+            Some(Value::Array(merged))
+        }
+        _ => ours.cloned(),
+    }
+}
+
+fn sidecar(path: &Path, extension: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(extension);
+    path.with_file_name(name)
+}
+
+/// Takes the advisory lock for `path`; released when the returned file is dropped.
+/// Filesystems without locking support are used unlocked rather than not at all.
+fn lock(path: &Path, exclusive: bool) -> io::Result<Option<File>> {
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sidecar(path, "lock"))?;
+    let locked = if exclusive { file.lock() } else { file.lock_shared() };
+    match locked {
+        Ok(()) => Ok(Some(file)),
+        Err(e) if e.kind() == io::ErrorKind::Unsupported => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// The latest snapshot waiting to be written. Newer snapshots replace older ones, so a burst of changes
+/// (e.g. a pack import touching the manifest once per mod) ends up as a single write.
+struct Pending {
+    path: PathBuf,
+    data: Vec<u8>,
+    seq: u64,
+    changed_at: Instant,
+}
+
+struct Saver {
+    pending: Mutex<(Option<Pending>, u64)>,
+    wake: Condvar,
+    /// Sequence number of the last snapshot on disk. Held from taking a snapshot until it is written, so
+    /// writes never overlap and `flush` can wait for one in flight. Always locked before `pending`.
+    written: Mutex<u64>,
+}
+
+static SAVER: OnceLock<Saver> = OnceLock::new();
+
+fn saver() -> &'static Saver {
+    SAVER.get_or_init(|| {
+        std::thread::spawn(run_saver);
+        Saver { pending: Mutex::new((None, 0)), wake: Condvar::new(), written: Mutex::new(0) }
+    })
+}
+
+/// Queues the JSON `data` to be written to `path` once changes settle, merged with whatever another process
+/// wrote there in the meantime. Call [`flush`] before exiting.
+pub fn schedule_write(path: PathBuf, data: Vec<u8>) {
+    let saver = saver();
+    let mut guard = saver.pending.lock().unwrap_or_else(|e| e.into_inner());
+    let (pending, next_seq) = &mut *guard;
+    *next_seq += 1;
+    *pending = Some(Pending { path, data, seq: *next_seq, changed_at: Instant::now() });
+    saver.wake.notify_all();
+}
+
+/// Writes any scheduled snapshot now and waits for writes in progress to finish.
+pub fn flush() {
+    let Some(saver) = SAVER.get() else { return };
+    // Snapshots are only taken while holding `written`, so once we have it no write is in flight.
+    let mut written = saver.written.lock().unwrap_or_else(|e| e.into_inner());
+    let pending = saver.pending.lock().unwrap_or_else(|e| e.into_inner()).0.take();
+    if let Some(pending) = pending {
+        write_pending(&mut written, pending);
+    }
+}
+
+fn run_saver() {
+    let saver = saver();
+    loop {
+        wait_until_due(saver);
+
+        let mut written = saver.written.lock().unwrap_or_else(|e| e.into_inner());
+        // A flush may have taken the snapshot meanwhile.
+        let pending = saver.pending.lock().unwrap_or_else(|e| e.into_inner()).0.take();
+        if let Some(pending) = pending {
+            write_pending(&mut written, pending);
+        }
+    }
+}
+
+/// Blocks until a snapshot has been pending for the debounce time.
+fn wait_until_due(saver: &Saver) {
+    let mut guard = saver.pending.lock().unwrap_or_else(|e| e.into_inner());
+    loop {
+        let Some(p) = &guard.0 else {
+            guard = saver.wake.wait(guard).unwrap_or_else(|e| e.into_inner());
+            continue;
+        };
+        let remaining = DEBOUNCE.saturating_sub(p.changed_at.elapsed());
+        if remaining.is_zero() {
+            return;
+        }
+        guard = saver.wake.wait_timeout(guard, remaining).unwrap_or_else(|e| e.into_inner()).0;
+    }
+}
+
+fn write_pending(written: &mut u64, pending: Pending) {
+    // Snapshots are taken in order under `written`, so an older one should never show up; skip it if it does.
+    if pending.seq <= *written {
+        return;
+    }
+    match write_merged(&pending.path, &pending.data) {
+        Ok(()) => *written = pending.seq,
+        Err(e) => eprintln!("Failed to save {:?}: {}", pending.path, e),
+    }
+}
@@ -0,0 +1,18 @@
+use pulldown_cmark::{html, Options, Parser};
+
+/// Cleans provider HTML (descriptions, changelogs) for display: scripts, styles, event handlers and
+/// non-web URLs are dropped, links get `rel="noopener noreferrer"`.
+pub fn sanitize_html(html: &str) -> String {
+    ammonia::Builder::default()
+        .url_schemes(["http", "https", "mailto"].into())
+        .clean(html)
+        .to_string()
+}
+
+/// Renders Markdown (Modtale bodies and changelogs) to sanitized HTML.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut out = String::new();
+    html::push_html(&mut out, Parser::new_ext(markdown, options));
+    sanitize_html(&out)
+}
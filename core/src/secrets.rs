@@ -0,0 +1,230 @@
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::persist::write_private;
+use crate::settings::{config_dir, ApiProvider};
+
+const KEYRING_SERVICE: &str = "hytale-mod-manager";
+const FILE_MAGIC: &[u8] = b"HMMSECRETS1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Where an API key was found or stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeySource {
+    /// `HYTALEMM_<PROVIDER>_API_KEY`; read-only and takes precedence over everything else.
+    Environment,
+    /// The OS secret store: Keychain, Windows Credential Manager or the Secret Service.
+    Keyring,
+    /// `secrets.enc` in the config folder, for machines without a secret store (e.g. headless Linux).
+    EncryptedFile,
+}
+
+impl KeySource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeySource::Environment => "environment variable",
+            KeySource::Keyring => "system keyring",
+            KeySource::EncryptedFile => "encrypted key file",
+        }
+    }
+}
+
+/// A provider's key and where it came from; `None` once a lookup found nothing.
+type StoredKey = Option<(String, KeySource)>;
+
+/// Keys already looked up this session; the keyring can be slow and may prompt on some desktops.
+static CACHE: Mutex<Option<HashMap<String, StoredKey>>> = Mutex::new(None);
+
+fn slug(provider: &ApiProvider) -> &'static str {
+    match provider {
+        ApiProvider::CurseForge => "curseforge",
+        ApiProvider::Modtale => "modtale",
+    }
+}
+
+/// Name of the environment variable that supplies the key for `provider`, e.g. `HYTALEMM_CURSEFORGE_API_KEY`.
+pub fn env_var(provider: &ApiProvider) -> String {
+    format!("HYTALEMM_{}_API_KEY", slug(provider).to_uppercase())
+}
+
+pub fn api_key(provider: &ApiProvider) -> Option<String> {
+    lookup(provider).map(|(key, _)| key)
+}
+
+pub fn key_source(provider: &ApiProvider) -> Option<KeySource> {
+    lookup(provider).map(|(_, source)| source)
+}
+
+fn lookup(provider: &ApiProvider) -> StoredKey {
+    if let Some(key) = std::env::var(env_var(provider)).ok().filter(|k| !k.trim().is_empty()) {
+        return Some((key.trim().to_string(), KeySource::Environment));
+    }
+
+    let name = slug(provider);
+    if let Some(known) = CACHE.lock().unwrap_or_else(|e| e.into_inner()).as_ref().and_then(|c| c.get(name)) {
+        return known.clone();
+    }
+
+    // Looked up without holding the cache, so a slow or prompting keyring does not hold up other lookups.
+    let found = keyring_get(name).ok().flatten().map(|key| (key, KeySource::Keyring))
+        .or_else(|| read_file().ok()?.remove(name).map(|key| (key, KeySource::EncryptedFile)));
+
+    // A key stored meanwhile is newer than what we found.
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    cache.get_or_insert_with(HashMap::new)
+        .entry(name.to_string())
+        .or_insert(found)
+        .clone()
+}
+
+/// Stores `key` for `provider`, in the keyring if there is one and in the encrypted file otherwise.
+/// An empty key removes the stored one.
+pub fn set_api_key(provider: &ApiProvider, key: &str) -> Result<Option<KeySource>, String> {
+    let name = slug(provider);
+    let key = key.trim();
+
+    let stored = if key.is_empty() {
+        let _ = keyring_delete(name);
+        update_file(|secrets| { secrets.remove(name); })?;
+        None
+    } else {
+        match keyring_set(name, key) {
+            Ok(()) => {
+                // Don't leave an older copy behind in the file.
+                let _ = update_file(|secrets| { secrets.remove(name); });
+                Some(KeySource::Keyring)
+            }
+            Err(e) => {
+                eprintln!("[Secrets] Keyring unavailable ({}), using the encrypted key file", e);
+                update_file(|secrets| { secrets.insert(name.to_string(), key.to_string()); })?;
+                Some(KeySource::EncryptedFile)
+            }
+        }
+    };
+
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    cache.get_or_insert_with(HashMap::new)
+        .insert(name.to_string(), stored.map(|source| (key.to_string(), source)));
+    Ok(stored)
+}
+
+// The Secret Service backend drives its own async runtime and refuses to start inside another one,
+// so keyring calls always run on a plain thread.
+fn on_plain_thread<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    std::thread::scope(|scope| scope.spawn(f).join().expect("Keyring thread panicked"))
+}
+
+fn keyring_get(name: &str) -> Result<Option<String>, String> {
+    on_plain_thread(|| {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, name).map_err(|e| e.to_string())?;
+        match entry.get_password() {
+            Ok(key) => Ok(Some(key)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+fn keyring_set(name: &str, key: &str) -> Result<(), String> {
+    on_plain_thread(|| {
+        keyring::Entry::new(KEYRING_SERVICE, name)
+            .and_then(|entry| entry.set_password(key))
+            .map_err(|e| e.to_string())
+    })
+}
+
+fn keyring_delete(name: &str) -> Result<(), String> {
+    on_plain_thread(|| {
+        match keyring::Entry::new(KEYRING_SERVICE, name).and_then(|entry| entry.delete_credential()) {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+fn secrets_path() -> PathBuf {
+    config_dir().join("secrets.enc")
+}
+
+/// Material the file key is derived from. It deliberately lives outside the config folder, so a synced
+/// or backed-up copy of that folder is useless on its own. `HYTALEMM_SECRETS_PASSPHRASE` overrides it
+/// for containers without a stable machine id.
+fn machine_secret() -> Result<Vec<u8>, String> {
+    if let Ok(passphrase) = std::env::var("HYTALEMM_SECRETS_PASSPHRASE") {
+        if !passphrase.is_empty() {
+            return Ok(passphrase.into_bytes());
+        }
+    }
+    ["/etc/machine-id", "/var/lib/dbus/machine-id"]
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|id| id.trim().to_string())
+        .find(|id| !id.is_empty())
+        .map(String::into_bytes)
+        .ok_or_else(|| "No machine id found; set HYTALEMM_SECRETS_PASSPHRASE to use the encrypted key file".to_string())
+}
+
+fn cipher(salt: &[u8]) -> Result<ChaCha20Poly1305, String> {
+    let mut hasher = Sha256::new();
+    hasher.update(KEYRING_SERVICE.as_bytes());
+    hasher.update(salt);
+    hasher.update(machine_secret()?);
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&hasher.finalize())))
+}
+
+fn read_file() -> Result<HashMap<String, String>, String> {
+    let data = match fs::read(secrets_path()) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let body = data.strip_prefix(FILE_MAGIC)
+        .filter(|body| body.len() > SALT_LEN + NONCE_LEN)
+        .ok_or("secrets.enc is not a key file")?;
+    let (salt, rest) = body.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let plain = cipher(salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "secrets.enc could not be decrypted on this machine".to_string())?;
+    serde_json::from_slice(&plain).map_err(|e| e.to_string())
+}
+
+fn update_file(change: impl FnOnce(&mut HashMap<String, String>)) -> Result<(), String> {
+    let path = secrets_path();
+    let mut secrets = read_file().unwrap_or_else(|e| {
+        eprintln!("[Secrets] Replacing unreadable key file: {}", e);
+        HashMap::new()
+    });
+    change(&mut secrets);
+
+    if secrets.is_empty() {
+        return match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.to_string()),
+        };
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let plain = serde_json::to_vec(&secrets).map_err(|e| e.to_string())?;
+    let ciphertext = cipher(&salt)?
+        .encrypt(&nonce, plain.as_slice())
+        .map_err(|_| "Failed to encrypt API keys".to_string())?;
+
+    let mut data = FILE_MAGIC.to_vec();
+    data.extend_from_slice(&salt);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&ciphertext);
+    write_private(&path, &data).map_err(|e| format!("Failed to write key file: {}", e))
+}
@@ -0,0 +1,799 @@
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::io;
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use crate::curse_forge_api::{cached_game_id, set_game_id as set_curseforge_game_id, set_global_api_key as set_curseforge_key};
+use crate::direct_install::local_entry;
+use crate::local_mods::{extract_base_name, is_mod_file};
+use crate::mod_tale_api::set_global_api_key as set_modtale_key;
+use crate::image_cache::DEFAULT_IMAGE_CACHE_MB;
+use crate::persist;
+use crate::ui_mod::ModMetadata;
+use crate::secrets::{self, KeySource};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ApiProvider {
+    Modtale,
+    CurseForge,
+}
+
+impl ApiProvider {
+    pub const ALL: [ApiProvider; 2] = [ApiProvider::CurseForge, ApiProvider::Modtale];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ApiProvider::Modtale => "ModTale",
+            ApiProvider::CurseForge => "CurseForge",
+        }
+    }
+}
+
+/// Outcome of trying an API key against its provider.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyStatus {
+    Valid,
+    /// Refused, with the reason.
+    Invalid(String),
+    /// Has the right shape but is refused; CurseForge keys take a few hours to activate after creation.
+    NotYetActive,
+    /// The provider could not be reached, so the key was not checked.
+    Unverified(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum AppTheme {
+    Dark,
+    Light,
+}
+
+/// What kind of content a project ships, which decides where it gets installed.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContentType {
+    #[default]
+    Plugin,
+    AssetPack,
+    World,
+    Prefab,
+}
+
+impl ContentType {
+    pub const ALL: [ContentType; 4] = [ContentType::Plugin, ContentType::AssetPack, ContentType::World, ContentType::Prefab];
+
+    /// Maps a CurseForge class name (the `name` of a category with `isClass`) to a content type.
+    pub fn from_curseforge_class(class_name: &str) -> Self {
+        match class_name.trim().to_lowercase().as_str() {
+            "mods" | "plugins" | "modpacks" => ContentType::Plugin,
+            "asset packs" | "texture packs" | "resource packs" => ContentType::AssetPack,
+            "worlds" | "maps" => ContentType::World,
+            "prefabs" => ContentType::Prefab,
+            _ => Self::guess(class_name),
+        }
+    }
+
+    /// Maps a Modtale classification (its project type, e.g. "PLUGIN", "SAVE") to a content type.
+    pub fn from_modtale_classification(classification: &str) -> Self {
+        match classification.trim().to_uppercase().replace([' ', '-'], "_").as_str() {
+            "PLUGIN" | "MODPACK" => ContentType::Plugin,
+            "DATA" | "ART" | "ASSET_PACK" => ContentType::AssetPack,
+            "SAVE" | "WORLD" => ContentType::World,
+            "PREFAB" => ContentType::Prefab,
+            _ => Self::guess(classification),
+        }
+    }
+
+    /// Last resort for labels neither provider is known to use.
+    fn guess(label: &str) -> Self {
+        let lower = label.to_lowercase();
+        let guessed = if lower.contains("modpack") {
+            ContentType::Plugin
+        } else if lower.contains("world") {
+            ContentType::World
+        } else if lower.contains("prefab") {
+            ContentType::Prefab
+        } else if lower.contains("asset") || lower.contains("texture") || lower.contains("resource") {
+            ContentType::AssetPack
+        } else {
+            ContentType::Plugin
+        };
+        // Every search result goes through here, so each label is reported once.
+        static REPORTED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+        if REPORTED.get_or_init(Default::default).lock().unwrap().insert(label.to_string()) {
+            eprintln!("[Content] Unknown project type {:?}, treating it as {}", label, guessed.label());
+        }
+        guessed
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContentType::Plugin => "Mods",
+            ContentType::AssetPack => "Asset Packs",
+            ContentType::World => "Worlds",
+            ContentType::Prefab => "Prefabs",
+        }
+    }
+
+    /// Worlds and prefab bundles are unpacked into a folder instead of being copied as an archive.
+    pub fn is_extracted(&self) -> bool {
+        matches!(self, ContentType::World | ContentType::Prefab)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InstalledModEntry {
+    pub mod_id: String,
+    pub mod_name: String,
+    pub file_id: String,
+    pub version_name: String,
+    pub api_provider: ApiProvider,
+    #[serde(default)]
+    pub content_type: ContentType,
+    /// Provider details as of the last successful fetch; `None` for local files and older manifests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ModMetadata>,
+}
+
+/// Where an installation keeps its mods, relative to its root.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ModsLayout {
+    /// Launcher install: `UserData/Mods`.
+    Client,
+    /// Dedicated server: `mods`.
+    Server,
+    /// Any other folder, relative to the root or absolute.
+    Custom(PathBuf),
+}
+
+impl ModsLayout {
+    pub fn mods_dir(&self, root: &Path) -> PathBuf {
+        match self {
+            ModsLayout::Client => root.join("UserData").join("Mods"),
+            ModsLayout::Server => root.join("mods"),
+            ModsLayout::Custom(path) => root.join(path),
+        }
+    }
+
+    /// Destination folder for each kind of content. Plugins and asset packs both load from the mods folder.
+    pub fn content_dir(&self, root: &Path, content_type: ContentType) -> PathBuf {
+        match (self, content_type) {
+            (_, ContentType::Plugin | ContentType::AssetPack) => self.mods_dir(root),
+            (ModsLayout::Server, ContentType::World) => root.join("universe").join("worlds"),
+            (ModsLayout::Server, ContentType::Prefab) => root.join("prefabs"),
+            (ModsLayout::Client, ContentType::World) => root.join("UserData").join("Saves"),
+            (ModsLayout::Client, ContentType::Prefab) => root.join("UserData").join("Prefabs"),
+            (ModsLayout::Custom(_), ContentType::World) => root.join("Saves"),
+            (ModsLayout::Custom(_), ContentType::Prefab) => root.join("Prefabs"),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ModsLayout::Client => "Client",
+            ModsLayout::Server => "Server",
+            ModsLayout::Custom(_) => "Custom",
+        }
+    }
+}
+
+/// A named Hytale installation or server directory with its own manifest.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameInstance {
+    pub name: String,
+    pub root: Option<PathBuf>,
+    pub layout: ModsLayout,
+    #[serde(default)]
+    pub installed_mods: HashMap<String, InstalledModEntry>,
+}
+
+impl GameInstance {
+    pub fn new(name: String, root: Option<PathBuf>, layout: ModsLayout) -> Self {
+        Self {
+            name,
+            root,
+            layout,
+            installed_mods: HashMap::new(),
+        }
+    }
+
+    pub fn mods_dir(&self) -> Option<PathBuf> {
+        self.root.as_ref().map(|root| self.layout.mods_dir(root))
+    }
+
+    pub fn content_dir(&self, content_type: ContentType) -> Option<PathBuf> {
+        self.root.as_ref().map(|root| self.layout.content_dir(root, content_type))
+    }
+}
+
+/// An author whose new releases show up in the updates feed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FollowedAuthor {
+    pub provider: ApiProvider,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub url: String,
+    /// Upload date of the newest file already seen; anything newer counts as a new release.
+    #[serde(default)]
+    pub seen_until: String,
+}
+
+/// Version of the settings file layout. Bump it and append a step to `MIGRATIONS` when the layout changes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// `MIGRATIONS[n]` upgrades a version `n` file to version `n + 1`.
+const MIGRATIONS: [fn(&mut Map<String, Value>); SCHEMA_VERSION as usize] = [
+    migrate_to_instances,
+];
+
+/// Why the settings file could not be used, and where the unreadable original was copied to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadFailure {
+    pub error: String,
+    pub backup: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AppSettings {
+    pub schema_version: u32,
+    /// Key for `api_provider`, loaded from the secret store. Never written to settings.json.
+    #[serde(skip)]
+    pub api_key: Option<String>,
+    pub theme: AppTheme,
+    pub api_provider: ApiProvider,
+    #[serde(default)]
+    pub instances: Vec<GameInstance>,
+    #[serde(default)]
+    pub active_instance: usize,
+    #[serde(default = "default_cache_limit_mb")]
+    pub download_cache_limit_mb: u64,
+    #[serde(default = "default_image_cache_limit_mb")]
+    pub image_cache_limit_mb: u64,
+    /// CurseForge's id for Hytale, found when the key was last validated.
+    pub curseforge_game_id: Option<u32>,
+    #[serde(default)]
+    pub followed_authors: Vec<FollowedAuthor>,
+
+    /// Fields this version does not know (e.g. written by a newer release), kept so saving does not drop them.
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+
+    /// Set when the file on disk could not be loaded. Saving is suppressed until the user decides what to do.
+    #[serde(skip)]
+    recovery: Option<LoadFailure>,
+}
+
+/// Hands every provider's stored key to its client. The clients are process-wide and also serve content
+/// from a provider other than the selected one (deep links, modpacks), so each needs its own key.
+fn apply_stored_keys() {
+    for provider in ApiProvider::ALL {
+        let key = secrets::api_key(&provider).unwrap_or_default();
+        match provider {
+            ApiProvider::CurseForge => set_curseforge_key(&key),
+            ApiProvider::Modtale => set_modtale_key(&key)
+        }
+    }
+}
+
+/// Stores `api_key` for `provider` in the secret store and hands the stored keys to the clients. A key set
+/// in the environment wins and is left alone. Waits on the keyring, so the app calls it off the UI thread.
+pub fn store_api_key(provider: &ApiProvider, api_key: &str) -> Result<(), String> {
+    if secrets::key_source(provider) != Some(KeySource::Environment) {
+        secrets::set_api_key(provider, api_key)?;
+    }
+    apply_stored_keys();
+    Ok(())
+}
+
+fn default_cache_limit_mb() -> u64 {
+    1024
+}
+
+fn default_image_cache_limit_mb() -> u64 {
+    DEFAULT_IMAGE_CACHE_MB
+}
+
+/// Schema 0 to 1: the single game folder and manifest become the first instance.
+fn migrate_to_instances(fields: &mut Map<String, Value>) {
+    let game_folder = fields.remove("game_folder").unwrap_or(Value::Null);
+    let installed_mods = fields.remove("installed_mods").unwrap_or_else(|| json!({}));
+
+    // Early multi-instance builds already wrote instances without a schema version.
+    if !fields.contains_key("instances") {
+        fields.insert("instances".to_string(), json!([{
+            "name": "Hytale",
+            "root": game_folder,
+            "layout": "Client",
+            "installed_mods": installed_mods,
+        }]));
+    }
+}
+
+/// Copies `path` next to itself as `<name>.<label>-<unix time>.bak` and returns the copy's path.
+fn backup_file(path: &Path, label: &str) -> Option<PathBuf> {
+    let stamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let file_name = path.file_name()?.to_string_lossy().into_owned();
+    let backup = path.with_file_name(format!("{}.{}-{}.bak", file_name, label, stamp));
+    match fs::copy(path, &backup) {
+        Ok(_) => Some(backup),
+        Err(e) => {
+            eprintln!("Failed to back up {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+/// Removes `api_key` from the backups `backup_file` made of `path`, so the plaintext key doesn't live on in
+/// them after it moved to the secret store. Backups that aren't valid JSON are left alone.
+fn scrub_backups(path: &Path) {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else { return };
+    let prefix = format!("{}.", name.to_string_lossy());
+    let Ok(entries) = fs::read_dir(dir) else { return };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if !file_name.starts_with(&prefix) || !file_name.ends_with(".bak") {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(entry.path()) else { continue };
+        let Ok(mut value) = serde_json::from_str::<Value>(&content) else { continue };
+        let Some(fields) = value.as_object_mut() else { continue };
+        if fields.remove("api_key").is_some() {
+            if let Ok(json) = serde_json::to_string_pretty(&value) {
+                let _ = fs::write(entry.path(), json);
+            }
+        }
+    }
+}
+
+/// Where the settings file and other per-user state live.
+pub fn config_dir() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| {
+        PathBuf::from(".")
+    });
+
+    path.push("hytale-mod-manager");
+    path
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            api_key: None,
+            theme: AppTheme::Dark,
+            api_provider: ApiProvider::CurseForge,
+            instances: vec![GameInstance::new("Hytale".to_string(), None, ModsLayout::Client)],
+            active_instance: 0,
+            download_cache_limit_mb: default_cache_limit_mb(),
+            image_cache_limit_mb: default_image_cache_limit_mb(),
+            curseforge_game_id: None,
+            followed_authors: Vec::new(),
+            extra: Map::new(),
+            recovery: None,
+        }
+    }
+}
+
+impl AppSettings {
+    pub fn load() -> Self {
+        Self::load_with_backup(|path| backup_file(path, "corrupt"))
+    }
+
+    /// Loads settings.json again while in recovery, e.g. after it was fixed by hand. A file that is still
+    /// unreadable keeps the backup made on the first failure rather than getting another one.
+    pub fn retry_load(&self) -> Self {
+        let backup = self.recovery.as_ref().and_then(|r| r.backup.clone());
+        Self::load_with_backup(move |_| backup)
+    }
+
+    /// `backup` is called once if the file cannot be used, to keep a copy of it.
+    fn load_with_backup(backup: impl FnOnce(&Path) -> Option<PathBuf>) -> Self {
+        let path = Self::get_config_path();
+        if !path.exists() {
+            let mut default_settings = AppSettings::default();
+            default_settings.load_keys();
+            if let Err(e) = default_settings.save() {
+                eprintln!("Failed to create initial settings file: {}", e);
+            }
+            return default_settings;
+        }
+
+        let loaded = persist::read_locked(&path)
+            .map_err(|e| format!("Failed to read settings file: {}", e))
+            .and_then(|content| Self::parse(&path, &content));
+
+        match loaded {
+            Ok((mut settings, migrated)) => {
+                eprintln!("Loaded settings from: {:?}", path);
+
+                settings.migrate_plaintext_key();
+                settings.load_keys();
+                if migrated {
+                    // Written right away so the pre-migration backup is only taken once.
+                    if let Err(e) = settings.save_now() {
+                        eprintln!("Failed to save migrated settings: {}", e);
+                    }
+                }
+                if let Some(id) = settings.curseforge_game_id {
+                    set_curseforge_game_id(id);
+                }
+                settings
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                let backup = backup(&path);
+                let mut settings = AppSettings {
+                    recovery: Some(LoadFailure { error, backup }),
+                    ..AppSettings::default()
+                };
+                settings.load_keys();
+                settings
+            }
+        }
+    }
+
+    /// Parses settings.json, migrating older schemas. The flag is set when a migration ran.
+    fn parse(path: &Path, content: &str) -> Result<(Self, bool), String> {
+        let mut value: Value = serde_json::from_str(content)
+            .map_err(|e| format!("Failed to parse settings.json: {}", e))?;
+        let fields = value.as_object_mut().ok_or("settings.json does not contain a JSON object")?;
+
+        let version = fields.get("schema_version").and_then(Value::as_u64).unwrap_or(0) as u32;
+        let migrated = version < SCHEMA_VERSION;
+        if version > SCHEMA_VERSION {
+            eprintln!("settings.json is from a newer version (schema {}); unknown fields are kept as they are.", version);
+        } else if migrated {
+            backup_file(path, &format!("schema-{}", version));
+            for step in version..SCHEMA_VERSION {
+                MIGRATIONS[step as usize](fields);
+                eprintln!("Migrated settings from schema {} to {}", step, step + 1);
+            }
+            fields.insert("schema_version".to_string(), json!(SCHEMA_VERSION));
+        }
+
+        let mut settings: AppSettings = serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse settings.json: {}", e))?;
+        if settings.instances.is_empty() {
+            settings.instances = AppSettings::default().instances;
+        }
+        if settings.active_instance >= settings.instances.len() {
+            settings.active_instance = 0;
+        }
+        Ok((settings, migrated))
+    }
+
+    /// Queues the settings to be written. Saves arriving in quick succession are coalesced into one write;
+    /// call [`persist::flush`] (or use [`AppSettings::save_now`]) before the process exits.
+    pub fn save(&self) -> io::Result<()> {
+        if self.recovery.is_some() {
+            return Ok(());
+        }
+
+        let path = Self::get_config_path();
+
+        let key = self.api_key.as_deref().unwrap_or_default();
+        match self.api_provider {
+            ApiProvider::CurseForge => set_curseforge_key(key),
+            ApiProvider::Modtale => set_modtale_key(key)
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        persist::schedule_write(path, json.into_bytes());
+
+        Ok(())
+    }
+
+    /// Whether another process (e.g. the CLI) changed settings.json since we last read or wrote it. Our own
+    /// pending changes are written first, merged with theirs, so loading the file afterwards loses nothing.
+    pub fn changed_on_disk() -> bool {
+        persist::flush();
+        persist::changed_externally(&Self::get_config_path())
+    }
+
+    /// Writes the settings right away instead of batching them with later changes.
+    pub fn save_now(&self) -> io::Result<()> {
+        self.save()?;
+        persist::flush();
+        Ok(())
+    }
+
+    /// Moves an `api_key` left in settings.json by older versions into the secret store. The flattened
+    /// `extra` map picks the old field up; on failure it stays there and the move is retried next start.
+    fn migrate_plaintext_key(&mut self) {
+        let Some(Value::String(key)) = self.extra.remove("api_key") else { return };
+        if key.is_empty() {
+            let _ = self.save();
+            return;
+        }
+
+        match secrets::set_api_key(&self.api_provider, &key) {
+            Ok(source) => {
+                eprintln!("Moved the API key out of settings.json into the {}", source.map_or("secret store", |s| s.label()));
+                let _ = self.save();
+                scrub_backups(&Self::get_config_path());
+            }
+            Err(e) => {
+                eprintln!("Could not move the API key out of settings.json: {}", e);
+                self.extra.insert("api_key".to_string(), Value::String(key));
+            }
+        }
+    }
+
+    /// Picks up the selected provider's key and hands every stored key to its client. The keys live in the
+    /// secret store or the environment, never in settings.json, so every way of loading settings needs this.
+    fn load_keys(&mut self) {
+        self.api_key = secrets::api_key(&self.api_provider);
+        apply_stored_keys();
+    }
+
+    /// The load error when the settings file could not be used; `None` in normal operation.
+    pub fn recovery(&self) -> Option<&LoadFailure> {
+        self.recovery.as_ref()
+    }
+
+    /// Leaves recovery with default settings. The next save replaces the unreadable file; its backup stays.
+    pub fn start_fresh(&mut self) {
+        self.recovery = None;
+        self.load_keys();
+    }
+
+    fn get_config_path() -> PathBuf {
+        config_dir().join("settings.json")
+    }
+
+    /// Switches provider and stores its key in the secret store. Fails if the key could not be stored.
+    pub fn change_api(&mut self, api_provider: ApiProvider, api_key: String) -> Result<(), String> {
+        store_api_key(&api_provider, &api_key)?;
+        self.select_api(api_provider);
+        Ok(())
+    }
+
+    /// Switches provider, using the key already stored for it (see [`store_api_key`]).
+    pub fn select_api(&mut self, api_provider: ApiProvider) {
+        let api_key = secrets::api_key(&api_provider).unwrap_or_default();
+        self.api_provider = api_provider;
+        self.api_key = Some(api_key);
+        self.curseforge_game_id = cached_game_id().or(self.curseforge_game_id);
+    }
+
+    /// A copy of these settings that talks to `provider`, for fetching content from a provider other than the selected one.
+    pub fn for_provider(&self, provider: &ApiProvider) -> AppSettings {
+        if *provider == self.api_provider {
+            return self.clone();
+        }
+        AppSettings { api_provider: provider.clone(), api_key: secrets::api_key(provider), ..self.clone() }
+    }
+
+    pub fn get_api_key(&self) -> Option<String> {
+        self.api_key.clone()
+    }
+    pub fn get_api_provider(&self) -> ApiProvider {
+        self.api_provider.clone()
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) -> Result<(), String> {
+        self.change_api(self.api_provider.clone(), api_key)
+    }
+    pub fn set_api_provider(&mut self, api_provider: ApiProvider) {
+        self.api_provider = api_provider;
+    }
+
+    pub fn active_instance(&self) -> &GameInstance {
+        &self.instances[self.active_instance]
+    }
+    fn active_instance_mut(&mut self) -> &mut GameInstance {
+        &mut self.instances[self.active_instance]
+    }
+
+    /// The instance at `index`. Installs look their instance up by the index captured when they
+    /// started, so switching instances meanwhile does not redirect them.
+    pub fn instance(&self, index: usize) -> Option<&GameInstance> {
+        self.instances.get(index)
+    }
+
+    pub fn select_instance(&mut self, index: usize) {
+        if index < self.instances.len() {
+            self.active_instance = index;
+        }
+    }
+    pub fn add_instance(&mut self, instance: GameInstance) {
+        self.instances.push(instance);
+        self.active_instance = self.instances.len() - 1;
+    }
+    pub fn remove_instance(&mut self, index: usize) {
+        if self.instances.len() <= 1 || index >= self.instances.len() {
+            return;
+        }
+        self.instances.remove(index);
+        if self.active_instance >= index && self.active_instance > 0 {
+            self.active_instance -= 1;
+        }
+    }
+
+    /// Root folder of the selected instance.
+    pub fn get_game_folder(&self) -> Option<PathBuf> {
+        self.active_instance().root.clone()
+    }
+    pub fn set_game_folder(&mut self, game_folder: Option<PathBuf>) {
+        self.active_instance_mut().root = game_folder;
+    }
+
+    /// Mods folder of the selected instance, following its layout.
+    pub fn get_mods_dir(&self) -> Option<PathBuf> {
+        self.active_instance().mods_dir()
+    }
+
+    pub fn get_content_dir(&self, content_type: ContentType) -> Option<PathBuf> {
+        self.active_instance().content_dir(content_type)
+    }
+
+    /// Manifest of the selected instance, keyed by file or folder name inside its content folder.
+    pub fn installed_mods(&self) -> &HashMap<String, InstalledModEntry> {
+        &self.active_instance().installed_mods
+    }
+
+    pub fn get_theme(&self) -> AppTheme {
+        self.theme.clone()
+    }
+    pub fn set_theme(&mut self, theme: AppTheme) {
+        self.theme = theme;
+    }
+    pub fn switch_theme(&mut self) {
+        match self.theme {
+            AppTheme::Light => { self.theme = AppTheme::Dark  }
+            AppTheme::Dark => { self.theme = AppTheme::Light }
+        }
+    }
+
+    pub fn get_download_cache_limit_mb(&self) -> u64 {
+        self.download_cache_limit_mb
+    }
+    pub fn set_download_cache_limit_mb(&mut self, limit_mb: u64) {
+        self.download_cache_limit_mb = limit_mb;
+    }
+
+    pub fn get_image_cache_limit_mb(&self) -> u64 {
+        self.image_cache_limit_mb
+    }
+    pub fn set_image_cache_limit_mb(&mut self, limit_mb: u64) {
+        self.image_cache_limit_mb = limit_mb;
+    }
+
+    pub fn followed_authors(&self) -> &[FollowedAuthor] {
+        &self.followed_authors
+    }
+
+    pub fn is_following(&self, provider: &ApiProvider, author_id: &str) -> bool {
+        self.followed_authors.iter().any(|a| a.provider == *provider && a.id == author_id)
+    }
+
+    pub fn follow_author(&mut self, author: FollowedAuthor) {
+        if !self.is_following(&author.provider, &author.id) {
+            self.followed_authors.push(author);
+        }
+    }
+
+    pub fn unfollow_author(&mut self, provider: &ApiProvider, author_id: &str) {
+        self.followed_authors.retain(|a| !(a.provider == *provider && a.id == author_id));
+    }
+
+    /// Marks everything up to `upload_date` as seen for one author.
+    pub fn mark_author_seen(&mut self, provider: &ApiProvider, author_id: &str, upload_date: &str) {
+        if let Some(author) = self.followed_authors.iter_mut().find(|a| a.provider == *provider && a.id == author_id) {
+            if upload_date > author.seen_until.as_str() {
+                author.seen_until = upload_date.to_string();
+            }
+        }
+    }
+
+    pub fn add_installed_mod(&mut self, instance: usize, filename: String, entry: InstalledModEntry) {
+        let Some(instance) = self.instances.get_mut(instance) else { return };
+        instance.installed_mods.insert(filename, entry);
+        let _ = self.save();
+    }
+
+    pub fn remove_installed_mod(&mut self, instance: usize, filename: &str) {
+        let Some(instance) = self.instances.get_mut(instance) else { return };
+        instance.installed_mods.remove(filename);
+        let _ = self.save();
+    }
+
+    pub fn get_installed_mod(&self, filename: &str) -> Option<&InstalledModEntry> {
+        self.installed_mods().get(filename)
+    }
+
+    /// Stores fresh provider details for an installed file of `instance`. Returns whether anything
+    /// changed, so unchanged details do not rewrite the settings file.
+    pub fn update_metadata(&mut self, instance: usize, filename: &str, metadata: ModMetadata) -> bool {
+        let Some(entry) = self.instances.get_mut(instance)
+            .and_then(|i| i.installed_mods.get_mut(filename)) else { return false };
+        if entry.metadata.as_ref() == Some(&metadata) {
+            return false;
+        }
+        entry.metadata = Some(metadata);
+        let _ = self.save();
+        true
+    }
+
+    pub fn prune_manifest(&mut self) {
+        if self.get_game_folder().is_some() {
+            let keys_to_remove: Vec<String> = self.installed_mods()
+                .iter()
+                .filter(|(filename, entry)| {
+                    self.get_content_dir(entry.content_type)
+                        .is_some_and(|dir| !dir.join(filename).exists())
+                })
+                .map(|(filename, _)| filename.clone())
+                .collect();
+
+            if !keys_to_remove.is_empty() {
+                let manifest = &mut self.active_instance_mut().installed_mods;
+                for key in keys_to_remove {
+                    manifest.remove(&key);
+                }
+                let _ = self.save();
+            }
+        }
+    }
+
+    /// Brings files added to the mods folder by hand into the manifest. A file that replaces one whose entry
+    /// lost its file (e.g. a jar swapped for a newer one) takes that entry over, keeping its provider ids but
+    /// marked as an unknown file; any other new file gets a local entry.
+    pub fn adopt_untracked_files(&mut self) {
+        let provider = self.api_provider.clone();
+        let Some(mods_path) = self.get_mods_dir() else { return };
+
+        let Ok(entries) = fs::read_dir(&mods_path) else { return };
+        let untracked: Vec<String> = entries
+            .flatten()
+            .filter(|e| is_mod_file(&e.path()))
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .filter(|name| !self.installed_mods().contains_key(name))
+            .collect();
+
+        let mut changed = false;
+        for filename in untracked {
+            let (base_name, version) = extract_base_name(&filename);
+
+            let manifest = &mut self.active_instance_mut().installed_mods;
+            let orphan_key = manifest
+                .iter()
+                .find(|(key, entry)| {
+                    !entry.content_type.is_extracted()
+                        && !mods_path.join(key).exists()
+                        && extract_base_name(key).0 == base_name
+                })
+                .map(|(key, _)| key.clone());
+
+            if let Some(orphan_key) = orphan_key {
+                if let Some(mut entry) = manifest.remove(&orphan_key) {
+                    eprintln!("[Manifest] Adopted {} (was {})", filename, orphan_key);
+                    entry.file_id = String::new();
+                    entry.version_name = version;
+                    manifest.insert(filename, entry);
+                    changed = true;
+                }
+                continue;
+            }
+
+            match fs::read(mods_path.join(&filename)) {
+                Ok(data) => {
+                    eprintln!("[Manifest] Tracking new local file {}", filename);
+                    let entry = local_entry(&filename, &data, ContentType::Plugin, &provider);
+                    manifest.insert(filename, entry);
+                    changed = true;
+                }
+                Err(e) => eprintln!("[Manifest] Could not read new file {}: {}", filename, e),
+            }
+        }
+
+        if changed {
+            let _ = self.save();
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1076 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::{curse_forge_api, mod_tale_api, rich_text};
+use crate::curse_forge_api::{ApiFile, CurseForgeMod, FileDependency, ModFile};
+use crate::mod_tale_api::{ModTaleFile, ModTaleMod};
+use crate::capabilities::{capabilities, SortField};
+use crate::disk_cache::{file_key, DiskCache};
+use crate::settings::{ApiProvider, AppSettings, ContentType, FollowedAuthor, InstalledModEntry, KeyStatus};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UiModVersion {
+    pub file_id: String,
+    pub display_name: String,
+    pub file_name: String,
+    pub download_url: Option<String>,
+    pub release_type: u8,
+    pub game_versions: Vec<String>,
+    pub upload_date: String,
+    /// Lowercase hex SHA-1 published by the provider, used to recognise the file after a manual download.
+    pub sha1: Option<String>,
+    pub file_size: Option<u64>,
+    pub dependencies: Vec<UiDependency>,
+}
+
+/// How a file relates to another mod, as CurseForge's `FileRelationType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DependencyRelation {
+    /// Shipped inside the file; nothing to install.
+    Embedded,
+    Optional,
+    Required,
+    /// A tool used to build or work with the mod, not needed to play.
+    Tool,
+    Incompatible,
+    /// Bundled with the file like `Embedded`.
+    Include,
+}
+
+impl DependencyRelation {
+    pub fn from_curseforge(relation_type: i32) -> Option<Self> {
+        match relation_type {
+            1 => Some(Self::Embedded),
+            2 => Some(Self::Optional),
+            3 => Some(Self::Required),
+            4 => Some(Self::Tool),
+            5 => Some(Self::Incompatible),
+            6 => Some(Self::Include),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Embedded => "Embedded",
+            Self::Optional => "Optional",
+            Self::Required => "Required",
+            Self::Tool => "Tool",
+            Self::Incompatible => "Incompatible",
+            Self::Include => "Included",
+        }
+    }
+
+    /// Whether the other mod can be installed alongside this one.
+    pub fn is_installable(&self) -> bool {
+        matches!(self, Self::Required | Self::Optional)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UiDependency {
+    pub mod_id: String,
+    pub relation: DependencyRelation,
+}
+
+fn curseforge_dependencies(dependencies: &[FileDependency]) -> Vec<UiDependency> {
+    dependencies.iter()
+        .filter_map(|d| Some(UiDependency {
+            mod_id: d.mod_id.to_string(),
+            relation: DependencyRelation::from_curseforge(d.relation_type)?,
+        }))
+        .collect()
+}
+
+impl UiModVersion {
+    pub fn from_curseforge_mod_file(file: &ModFile) -> Self {
+        Self {
+            file_id: file.id.to_string(),
+            display_name: file.display_name.clone(),
+            file_name: file.file_name.clone(),
+            download_url: file.download_url.clone(),
+            release_type: file.release_type as u8,
+            game_versions: file.game_versions.clone(),
+            upload_date: file.file_date.clone(),
+            sha1: curse_forge_api::sha1_of(&file.hashes),
+            file_size: Some(file.file_length),
+            dependencies: curseforge_dependencies(&file.dependencies),
+        }
+    }
+
+    pub fn from_curseforge_api_file(file: &ApiFile) -> Self {
+        Self {
+            file_id: file.id.to_string(),
+            display_name: file.display_name.clone(),
+            file_name: file.file_name.clone(),
+            download_url: file.download_url.clone(),
+            release_type: file.release_type as u8,
+            game_versions: file.game_versions.clone(),
+            upload_date: file.file_date.clone(),
+            sha1: curse_forge_api::sha1_of(&file.hashes),
+            file_size: Some(file.file_length),
+            dependencies: curseforge_dependencies(&file.dependencies),
+        }
+    }
+
+    pub fn from_modtale_file(file: &ModTaleFile) -> Self {
+        let release_type = match file.channel.as_deref() {
+            Some("BETA") => 2,
+            Some("ALPHA") => 3,
+            _ => 1,
+        };
+
+        let file_name = file.download_url.as_ref()
+            .and_then(|url| url.split('/').last())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| format!("{}.jar", file.version_number));
+
+        Self {
+            file_id: file.id.clone(),
+            display_name: file.version_number.clone(),
+            file_name,
+            download_url: file.download_url.clone(),
+            release_type,
+            game_versions: file.supported_versions.clone(),
+            upload_date: file.created_at.clone(),
+            sha1: None,
+            file_size: None,
+            dependencies: vec![],
+        }
+    }
+}
+
+/// A project's author as the provider identifies them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UiAuthor {
+    /// CurseForge's numeric member id; the username on Modtale.
+    pub id: String,
+    pub name: String,
+    /// Profile page on the provider's website; empty when unknown.
+    pub url: String,
+}
+
+impl From<&FollowedAuthor> for UiAuthor {
+    fn from(followed: &FollowedAuthor) -> Self {
+        Self { id: followed.id.clone(), name: followed.name.clone(), url: followed.url.clone() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiMod {
+    pub id: String,
+    pub name: String,
+    pub summary: String,
+    /// Display form of `author_list`, e.g. "Alice, Bob".
+    pub authors: String,
+    pub author_list: Vec<UiAuthor>,
+    pub download_count: u64,
+    pub categories: Vec<String>,
+    pub icon: String,
+    pub banner: String,
+    pub gallery_urls: Vec<String>,
+    pub website_url: String,
+    pub content_type: ContentType,
+    pub version: UiModVersion,
+    /// False when the author only allows downloads through the provider's website, in which case files
+    /// come without a download URL and have to be fetched by hand (see `manual_download`).
+    pub allow_distribution: bool,
+}
+
+impl UiMod {
+    pub fn from_curseforge_api(api_mod: &CurseForgeMod) -> Self {
+        let authors = api_mod.authors.iter()
+            .map(|a| a.name.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let categories = api_mod.categories.iter()
+            .map(|c| c.name.clone())
+            .collect();
+
+        let icon = api_mod.logo.as_ref()
+            .map(|l| l.thumbnail_url.clone())
+            .unwrap_or_default();
+
+        let banner = api_mod.screenshots.first()
+            .map(|s| s.url.clone())
+            .unwrap_or_else(|| icon.clone());
+
+        let gallery_urls = api_mod.screenshots.iter()
+            .map(|s| s.url.clone())
+            .collect();
+
+        let version = if let Some(latest) = api_mod
+            .latest_files
+            .iter()
+            .find(|v| v.release_type == 1)
+            .or(api_mod.latest_files.first())
+        {
+            UiModVersion::from_curseforge_mod_file(latest)
+        } else {
+            UiModVersion_dummy()
+        };
+
+        Self {
+            id: api_mod.id.to_string(),
+            name: api_mod.name.clone(),
+            summary: api_mod.summary.clone(),
+            authors,
+            author_list: api_mod.authors.iter()
+                .map(|a| UiAuthor { id: a.id.to_string(), name: a.name.clone(), url: a.url.clone() })
+                .collect(),
+            download_count: api_mod.download_count as u64,
+            categories,
+            icon,
+            banner,
+            gallery_urls,
+            website_url: api_mod.links.website_url.clone(),
+            content_type: api_mod.class_id
+                .and_then(curse_forge_api::class_name)
+                .map(|name| ContentType::from_curseforge_class(&name))
+                .unwrap_or_default(),
+            version,
+            allow_distribution: api_mod.allow_mod_distribution != Some(false),
+        }
+    }
+
+    pub fn from_modtale_api(modtale_mod: &ModTaleMod) -> Self {
+        let icon = modtale_mod.icon_url.clone().unwrap_or_default();
+        let banner = modtale_mod.banner_url.clone().unwrap_or(icon.clone());
+
+        let version = if let Some(versions) = &modtale_mod.versions {
+            if let Some(first) = versions.first() {
+                UiModVersion::from_modtale_file(first)
+            } else {
+                UiModVersion_dummy()
+            }
+        } else {
+            UiModVersion_dummy()
+        };
+
+        let slug = modtale_mod.slug.clone().unwrap_or_else(|| modtale_mod.id.clone());
+
+        Self {
+            id: modtale_mod.id.clone(),
+            name: modtale_mod.name.clone(),
+            summary: modtale_mod.summary.clone().unwrap_or_default(),
+            authors: modtale_mod.author.clone(),
+            author_list: vec![UiAuthor {
+                id: modtale_mod.author.clone(),
+                name: modtale_mod.author.clone(),
+                url: String::new(),
+            }],
+            download_count: modtale_mod.download_count,
+            categories: modtale_mod.categories.clone().unwrap_or_default(),
+            icon,
+            banner,
+            gallery_urls: vec![],
+            website_url: format!("https://modtale.net/project/{}", slug),
+            content_type: modtale_mod.classification.as_deref()
+                .map(ContentType::from_modtale_classification)
+                .unwrap_or_default(),
+            version,
+            allow_distribution: true,
+        }
+    }
+
+    /// Whether `version` has to go through the manual download flow instead of a direct download.
+    pub fn needs_manual_download(&self, version: &UiModVersion) -> bool {
+        !self.allow_distribution && version.download_url.is_none() && self.id != "0"
+    }
+}
+
+/// What the provider last said about an installed mod, kept in the manifest so the Installed page can
+/// show it without a connection. Refreshed whenever the details are fetched again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModMetadata {
+    pub name: String,
+    pub summary: String,
+    pub icon: String,
+    pub authors: String,
+    #[serde(default)]
+    pub author_list: Vec<UiAuthor>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub website_url: String,
+    /// The newest file known at the time, with its dependencies.
+    pub latest_version: UiModVersion,
+    #[serde(default = "default_true")]
+    pub allow_distribution: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl ModMetadata {
+    pub fn from_mod(mod_data: &UiMod) -> Self {
+        Self {
+            name: mod_data.name.clone(),
+            summary: mod_data.summary.clone(),
+            icon: mod_data.icon.clone(),
+            authors: mod_data.authors.clone(),
+            author_list: mod_data.author_list.clone(),
+            categories: mod_data.categories.clone(),
+            website_url: mod_data.website_url.clone(),
+            latest_version: mod_data.version.clone(),
+            allow_distribution: mod_data.allow_distribution,
+        }
+    }
+
+    /// The mod as last seen, for the manifest entry it is stored with.
+    pub fn to_ui_mod(&self, entry: &InstalledModEntry) -> UiMod {
+        UiMod {
+            id: entry.mod_id.clone(),
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            authors: self.authors.clone(),
+            author_list: self.author_list.clone(),
+            download_count: 0,
+            categories: self.categories.clone(),
+            icon: self.icon.clone(),
+            banner: String::new(),
+            gallery_urls: vec![],
+            website_url: self.website_url.clone(),
+            content_type: entry.content_type,
+            version: self.latest_version.clone(),
+            allow_distribution: self.allow_distribution,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn UiModVersion_dummy() -> UiModVersion {
+    UiModVersion {
+        file_id: "".to_string(),
+        display_name: "No files".to_string(),
+        file_name: "".to_string(),
+        download_url: None,
+        release_type: 0,
+        game_versions: vec![],
+        upload_date: "".to_string(),
+        sha1: None,
+        file_size: None,
+        dependencies: vec![],
+    }
+}
+
+/// A category (CurseForge) or tag (Modtale) search results can be narrowed to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiCategory {
+    /// What the provider's search expects: a numeric id on CurseForge, the tag itself on Modtale.
+    pub id: String,
+    pub name: String,
+    /// Heading the category is listed under, e.g. its CurseForge class; empty when the provider has no groups.
+    pub group: String,
+    pub icon: String,
+}
+
+/// Narrowing applied on top of the query and sort order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SearchFilters {
+    /// `UiCategory::id`s; results match any of them.
+    pub categories: Vec<String>,
+}
+
+pub async fn get_categories_unified(provider: &ApiProvider) -> Result<Vec<UiCategory>, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            let categories = curse_forge_api::get_categories().await?;
+            let class_names: HashMap<u32, String> = categories.iter()
+                .filter(|c| c.is_class == Some(true))
+                .map(|c| (c.id, c.name.clone()))
+                .collect();
+
+            let mut ui_categories: Vec<UiCategory> = categories.iter()
+                .filter(|c| c.is_class != Some(true))
+                .map(|c| UiCategory {
+                    id: c.id.to_string(),
+                    name: c.name.clone(),
+                    group: c.class_id.and_then(|id| class_names.get(&id).cloned()).unwrap_or_default(),
+                    icon: c.icon_url.clone(),
+                })
+                .collect();
+            ui_categories.sort_by(|a, b| a.group.cmp(&b.group).then_with(|| a.name.cmp(&b.name)));
+            Ok(ui_categories)
+        }
+        ApiProvider::Modtale => {
+            let mut tags = mod_tale_api::get_tags().await?;
+            tags.sort();
+            Ok(tags.into_iter()
+                .map(|tag| UiCategory { id: tag.clone(), name: tag, group: String::new(), icon: String::new() })
+                .collect())
+        }
+    }
+}
+
+fn curseforge_sort(sort: SortField) -> Option<u32> {
+    // CurseForge's ModsSearchSortField values.
+    match sort {
+        SortField::Featured => Some(1),
+        SortField::Popularity => Some(2),
+        SortField::LastUpdated => Some(3),
+        SortField::Name => Some(4),
+        SortField::Downloads => Some(6),
+        SortField::Created => Some(11),
+        SortField::Rating => Some(12),
+        SortField::Relevance => None,
+    }
+}
+
+fn modtale_sort(sort: SortField) -> Option<&'static str> {
+    match sort {
+        SortField::Relevance => Some("relevance"),
+        SortField::Downloads => Some("downloads"),
+        SortField::LastUpdated => Some("updated"),
+        _ => None,
+    }
+}
+
+/// Searches the active provider. Options its [`Capabilities`](crate::capabilities::Capabilities) don't list
+/// are refused with an error rather than quietly swapped for something else.
+pub async fn search_mods_unified(
+    settings: &AppSettings,
+    sort: SortField,
+    query: String,
+    offset: u32,
+    page_size: u32,
+    filters: &SearchFilters,
+) -> Result<(Vec<UiMod>, u32), String> {
+    let caps = capabilities(&settings.api_provider);
+    let provider_name = settings.api_provider.label();
+    if !caps.supports_sort(sort) {
+        return Err(format!("{} cannot sort by {}", provider_name, sort.label()));
+    }
+    if !caps.page_sizes.contains(&page_size) {
+        return Err(format!("{} does not offer {} results per page", provider_name, page_size));
+    }
+    if !filters.categories.is_empty() && !caps.category_filter {
+        return Err(format!("{} cannot filter by category", provider_name));
+    }
+    if let Some(max) = caps.max_categories.filter(|max| filters.categories.len() > *max) {
+        return Err(format!("{} filters by at most {} categories at once", provider_name, max));
+    }
+
+    match settings.api_provider {
+        ApiProvider::CurseForge => {
+            let sort = curseforge_sort(sort).ok_or_else(|| format!("{} cannot sort by {}", provider_name, sort.label()))?;
+
+            if let Err(e) = curse_forge_api::load_classes().await {
+                eprintln!("CurseForge classes fetch failed: {}", e);
+            }
+
+            let category_ids: Vec<u32> = filters.categories.iter()
+                .filter_map(|id| id.parse().ok())
+                .collect();
+
+            match curse_forge_api::search_mods(query, sort, offset, page_size, &category_ids).await {
+                Ok((api_mods, pagination)) => {
+                    let ui_mods: Vec<UiMod> = api_mods.iter()
+                        .map(|m| UiMod::from_curseforge_api(m))
+                        .collect();
+
+                    let total_pages = if let Some(p) = pagination {
+                        (p.total_count as f64 / p.page_size as f64).ceil() as u32
+                    } else {
+                        0
+                    };
+
+                    Ok((ui_mods, total_pages))
+                }
+                Err(e) => Err(e),
+            }
+        }
+        ApiProvider::Modtale => {
+            let sort = modtale_sort(sort).ok_or_else(|| format!("{} cannot sort by {}", provider_name, sort.label()))?;
+            match mod_tale_api::search_mods(query, sort, offset, page_size, &filters.categories).await {
+                Ok((api_mods, meta)) => {
+                    let ui_mods: Vec<UiMod> = api_mods.iter()
+                        .map(|m| UiMod::from_modtale_api(m))
+                        .collect();
+
+                    let total_pages = if let Some(m) = meta {
+                        m.last_page
+                    } else {
+                        0
+                    };
+
+                    Ok((ui_mods, total_pages))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+/// The provider's curated lists as titled sections. Empty for providers without `Capabilities::featured`.
+pub async fn get_featured_unified(provider: &ApiProvider) -> Result<Vec<(String, Vec<UiMod>)>, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            if let Err(e) = curse_forge_api::load_classes().await {
+                eprintln!("CurseForge classes fetch failed: {}", e);
+            }
+            let featured = curse_forge_api::get_featured().await?;
+            let section = |title: &str, mods: &[CurseForgeMod]| {
+                (title.to_string(), mods.iter().map(UiMod::from_curseforge_api).collect::<Vec<_>>())
+            };
+            Ok(vec![
+                section("Featured", &featured.featured),
+                section("Popular", &featured.popular),
+                section("Recently Updated", &featured.recently_updated),
+            ]
+            .into_iter()
+            .filter(|(_, mods)| !mods.is_empty())
+            .collect())
+        }
+        ApiProvider::Modtale => Ok(Vec::new()),
+    }
+}
+
+pub async fn check_key_unified(provider: &ApiProvider, key: &str) -> KeyStatus {
+    match provider {
+        ApiProvider::CurseForge => curse_forge_api::check_key(key).await,
+        ApiProvider::Modtale => mod_tale_api::check_key(key).await,
+    }
+}
+
+pub async fn search_exact_mod_unified(
+    provider: &ApiProvider,
+    query: String,
+) -> Result<UiMod, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            if let Some(cf_mod) = curse_forge_api::search_exact_mod(query).await {
+                return Ok(UiMod::from_curseforge_api(&cf_mod));
+            }
+        },
+        ApiProvider::Modtale => {
+            if let Some(mt_mod) = mod_tale_api::search_exact_mod(query).await {
+                return Ok(UiMod::from_modtale_api(&mt_mod));
+            }
+        }
+    }
+
+    Err("Mod Not Found".to_string())
+}
+
+/// Release channels by `UiModVersion::release_type`.
+pub const CHANNELS: [(u8, &str); 3] = [(1, "Release"), (2, "Beta"), (3, "Alpha")];
+
+/// Looks a channel up by name, ignoring case.
+pub fn channel_release_type(name: &str) -> Option<u8> {
+    CHANNELS.iter()
+        .find(|(_, label)| label.eq_ignore_ascii_case(name))
+        .map(|(release_type, _)| *release_type)
+}
+
+/// Narrows a mod's version list; `None` fields match everything.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VersionFilter {
+    pub game_version: Option<String>,
+    /// One of the `CHANNELS`.
+    pub release_type: Option<u8>,
+}
+
+impl VersionFilter {
+    fn matches(&self, version: &UiModVersion) -> bool {
+        self.release_type.is_none_or(|t| t == version.release_type)
+            && self.game_version.as_ref().is_none_or(|g| version.game_versions.contains(g))
+    }
+}
+
+/// A slice of a mod's versions, newest first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VersionPage {
+    pub versions: Vec<UiModVersion>,
+    /// Offset to pass for the following page; `None` once there is nothing left.
+    pub next_offset: Option<u32>,
+    /// How many versions match the filter, when the provider can tell without listing them all.
+    pub total: Option<u32>,
+}
+
+/// One page of a mod's versions starting at `offset`, which is 0 or a previous page's `next_offset`.
+///
+/// CurseForge pages and filters by game version on the server. Its files endpoint has no release type
+/// parameter, so the channel is applied to each page as it arrives, fetching further pages until this
+/// one is full. Modtale sends a project's whole version list with the project, so it is sliced here.
+pub async fn get_mod_versions_page_unified(
+    settings: &AppSettings,
+    mod_id: &str,
+    filter: &VersionFilter,
+    offset: u32,
+    page_size: u32,
+) -> Result<VersionPage, String> {
+    match settings.api_provider {
+        ApiProvider::CurseForge => {
+            let cf_id = mod_id.parse::<u32>()
+                .map_err(|_| "Invalid ID format for CurseForge (expected number)".to_string())?;
+
+            let mut page = VersionPage::default();
+            let mut index = offset;
+            loop {
+                let (files, pagination) = curse_forge_api::get_mod_files(cf_id, filter.game_version.as_deref(), index, page_size)
+                    .await
+                    .inspect_err(|e| eprintln!("CurseForge Versions fetch FAILED: {}", e))?;
+
+                index += files.len() as u32;
+                let total = pagination.map(|p| p.total_count);
+                if filter.release_type.is_none() {
+                    page.total = total;
+                }
+                page.versions.extend(files.iter()
+                    .map(UiModVersion::from_curseforge_api_file)
+                    .filter(|v| filter.matches(v)));
+
+                let exhausted = files.is_empty() || total.is_none_or(|total| index >= total);
+                if exhausted {
+                    page.next_offset = None;
+                    return Ok(page);
+                }
+                page.next_offset = Some(index);
+                if page.versions.len() as u32 >= page_size {
+                    return Ok(page);
+                }
+            }
+        }
+        ApiProvider::Modtale => {
+            let files = mod_tale_api::get_mod_files(mod_id)
+                .await
+                .inspect_err(|e| eprintln!("ModTale Versions fetch FAILED: {}", e))?;
+
+            let matching: Vec<UiModVersion> = files.iter()
+                .map(UiModVersion::from_modtale_file)
+                .filter(|v| filter.matches(v))
+                .collect();
+            let end = (offset as usize + page_size as usize).min(matching.len());
+            let start = (offset as usize).min(end);
+
+            Ok(VersionPage {
+                versions: matching[start..end].to_vec(),
+                next_offset: (end < matching.len()).then_some(end as u32),
+                total: Some(matching.len() as u32),
+            })
+        }
+    }
+}
+
+/// Every version of a mod matching `filter`, following the pages to the end.
+pub async fn get_mod_versions_unified(
+    settings: &AppSettings,
+    mod_id: &str,
+    filter: &VersionFilter,
+) -> Result<Vec<UiModVersion>, String> {
+    let mut versions = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = get_mod_versions_page_unified(settings, mod_id, filter, offset, curse_forge_api::MAX_FILES_PAGE_SIZE).await?;
+        versions.extend(page.versions);
+        match page.next_offset {
+            Some(next) => offset = next,
+            None => return Ok(versions),
+        }
+    }
+}
+
+/// One specific version of a mod, fetched directly so it can be found however far back it is.
+pub async fn get_mod_version_unified(
+    settings: &AppSettings,
+    mod_id: &str,
+    file_id: &str,
+) -> Result<UiModVersion, String> {
+    match settings.api_provider {
+        ApiProvider::CurseForge => {
+            let (Ok(mod_num), Ok(file_num)) = (mod_id.parse::<u32>(), file_id.parse::<u32>()) else {
+                return Err(format!("Invalid CurseForge file id {}/{}", mod_id, file_id));
+            };
+            let file = curse_forge_api::get_mod_file(mod_num, file_num).await?;
+            Ok(UiModVersion::from_curseforge_api_file(&file))
+        }
+        ApiProvider::Modtale => {
+            let file = mod_tale_api::get_mod_file(mod_id, file_id).await?;
+            Ok(UiModVersion::from_modtale_file(&file))
+        }
+    }
+}
+
+/// Game versions the Versions tab can filter by: the game's full list on CurseForge, the versions the
+/// project's files declare on Modtale.
+pub async fn get_game_versions_unified(provider: &ApiProvider, mod_id: &str) -> Result<Vec<String>, String> {
+    match provider {
+        ApiProvider::CurseForge => curse_forge_api::get_game_versions().await,
+        ApiProvider::Modtale => {
+            let mut versions: Vec<String> = Vec::new();
+            for file in mod_tale_api::get_mod_files(mod_id).await? {
+                for version in file.supported_versions {
+                    if !versions.contains(&version) {
+                        versions.push(version);
+                    }
+                }
+            }
+            Ok(versions)
+        }
+    }
+}
+
+pub async fn download_version_unified(
+    settings: &AppSettings,
+    version: &UiModVersion,
+) -> Result<(String, Vec<u8>), String> {
+    let cache = DiskCache::downloads(settings.download_cache_limit_mb);
+    let cache_key = file_key(&settings.api_provider, &version.file_id);
+
+    if !version.file_id.is_empty() {
+        if let Some(bytes) = cache.get(&cache_key) {
+            eprintln!("[Cache] Hit for {}", version.file_name);
+            return Ok((version.file_name.clone(), bytes));
+        }
+    }
+
+    let url = version.download_url.as_ref()
+        .ok_or("No download URL available")?;
+
+    let bytes = match settings.api_provider {
+        ApiProvider::CurseForge => curse_forge_api::download_url(url).await?,
+        ApiProvider::Modtale => {
+            match mod_tale_api::download_url(url).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    eprintln!("ModTale Download FAILED: {}", e);
+                    return Err(e);
+                }
+            }
+        }
+    };
+
+    if !version.file_id.is_empty() {
+        if let Err(e) = cache.put(&cache_key, &version.file_name, &bytes) {
+            eprintln!("[Cache] Failed to store {}: {}", version.file_name, e);
+        }
+    }
+
+    Ok((version.file_name.clone(), bytes))
+}
+
+/// Looks up where to download exactly `file_id` of `mod_id`. `Ok(None)` means the file exists but the
+/// provider will not hand it out to third-party apps.
+pub async fn resolve_download_url_unified(
+    provider: &ApiProvider,
+    mod_id: &str,
+    file_id: &str,
+) -> Result<Option<String>, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            let (Ok(mod_num), Ok(file_num)) = (mod_id.parse::<u32>(), file_id.parse::<u32>()) else {
+                return Err(format!("Invalid CurseForge file id {}/{}", mod_id, file_id));
+            };
+            curse_forge_api::get_file_download_url(mod_num, file_num).await
+        }
+        ApiProvider::Modtale => {
+            Ok(mod_tale_api::get_mod_file(mod_id, file_id).await?.download_url)
+        }
+    }
+}
+
+pub async fn get_mod_details_unified(
+    provider: &ApiProvider,
+    mod_id: &str,
+) -> Option<UiMod> {
+    match provider {
+        ApiProvider::CurseForge => {
+            if let Ok(id_num) = mod_id.parse::<u32>() {
+                if let Err(e) = curse_forge_api::load_classes().await {
+                    eprintln!("CurseForge classes fetch failed: {}", e);
+                }
+
+                match curse_forge_api::get_mod(id_num).await {
+                    Ok(m) => Some(UiMod::from_curseforge_api(&m)),
+                    Err(e) => {
+                        eprintln!("CurseForge fetch failed for {}: {}", mod_id, e);
+                        None
+                    }
+                }
+            } else {
+                eprintln!("Invalid CurseForge ID: {}", mod_id);
+                None
+            }
+        }
+        ApiProvider::Modtale => {
+            match mod_tale_api::get_mod(mod_id).await {
+                Ok(m) => {
+                    Some(UiMod::from_modtale_api(&m))
+                }
+                Err(e) => {
+                    eprintln!("ModTale fetch FAILED for {}: {}", mod_id, e);
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// A mod's full description as sanitized HTML; empty if the provider has none beyond the summary.
+pub async fn get_mod_description_unified(provider: &ApiProvider, mod_id: &str) -> Result<String, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            let id = mod_id.parse::<u32>().map_err(|_| format!("Invalid CurseForge ID: {}", mod_id))?;
+            Ok(rich_text::sanitize_html(&curse_forge_api::get_mod_description(id).await?))
+        }
+        ApiProvider::Modtale => {
+            let body = mod_tale_api::get_mod(mod_id).await?.body.unwrap_or_default();
+            Ok(rich_text::markdown_to_html(&body))
+        }
+    }
+}
+
+/// One file's changelog as sanitized HTML; empty if the author wrote none.
+pub async fn get_changelog_unified(provider: &ApiProvider, mod_id: &str, file_id: &str) -> Result<String, String> {
+    match provider {
+        ApiProvider::CurseForge => {
+            let (Ok(mod_num), Ok(file_num)) = (mod_id.parse::<u32>(), file_id.parse::<u32>()) else {
+                return Err(format!("Invalid CurseForge file id {}/{}", mod_id, file_id));
+            };
+            Ok(rich_text::sanitize_html(&curse_forge_api::get_file_changelog(mod_num, file_num).await?))
+        }
+        ApiProvider::Modtale => {
+            let changelog = mod_tale_api::get_mod_file(mod_id, file_id).await?.changelog.unwrap_or_default();
+            Ok(rich_text::markdown_to_html(&changelog))
+        }
+    }
+}
+
+/// How many versions a combined changelog covers at most, so updating a long-neglected mod
+/// does not fire off a request per release it missed.
+pub const MAX_CHANGELOG_VERSIONS: usize = 25;
+
+/// What changed between the installed file and `target`: the changelog of every version after
+/// `installed_file_id` up to and including `target`, newest first. Rolling back, or an installed file
+/// the provider no longer lists, yields just the target's changelog.
+pub async fn get_changelog_between_unified(
+    settings: &AppSettings,
+    mod_id: &str,
+    installed_file_id: Option<&str>,
+    target: &UiModVersion,
+) -> Result<Vec<(UiModVersion, String)>, String> {
+    let mut between = Vec::new();
+    let mut offset = 0;
+    let mut reached_target = false;
+    'pages: loop {
+        let page = get_mod_versions_page_unified(settings, mod_id, &VersionFilter::default(), offset, curse_forge_api::MAX_FILES_PAGE_SIZE).await?;
+        for version in page.versions {
+            if installed_file_id == Some(version.file_id.as_str()) {
+                break 'pages;
+            }
+            reached_target |= version.file_id == target.file_id;
+            if reached_target {
+                between.push(version);
+                if between.len() >= MAX_CHANGELOG_VERSIONS {
+                    break 'pages;
+                }
+            }
+        }
+        match page.next_offset {
+            Some(next) => offset = next,
+            None => break,
+        }
+    }
+    if between.is_empty() {
+        between.push(target.clone());
+    }
+
+    let mut changelogs = Vec::with_capacity(between.len());
+    for version in between {
+        let html = get_changelog_unified(&settings.api_provider, mod_id, &version.file_id).await?;
+        changelogs.push((version, html));
+    }
+    Ok(changelogs)
+}
+
+/// A dependency of a file, directly or through one of its required dependencies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedDependency {
+    pub dependency: UiDependency,
+    /// The mod, with `version` set to the file to install; `None` when the mod no longer exists.
+    pub mod_data: Option<UiMod>,
+    /// Name of the dependency that needs this one; `None` for the file's own dependencies.
+    pub required_by: Option<String>,
+}
+
+/// Everything `version` of mod `mod_id` depends on: its own dependencies, then the required and
+/// incompatible dependencies of each required one, transitively. Each mod is listed once, where it is
+/// first reached. Installable dependencies come with their newest file for one of the game versions
+/// `version` supports.
+pub async fn resolve_dependencies_unified(
+    settings: &AppSettings,
+    mod_id: &str,
+    version: &UiModVersion,
+) -> Result<Vec<ResolvedDependency>, String> {
+    let mut visited: HashSet<String> = HashSet::from([mod_id.to_string()]);
+    let mut queue: VecDeque<(Vec<UiDependency>, Option<String>)> = VecDeque::from([(version.dependencies.clone(), None)]);
+    let mut resolved = Vec::new();
+
+    while let Some((dependencies, required_by)) = queue.pop_front() {
+        let dependencies: Vec<UiDependency> = dependencies.into_iter()
+            .filter(|d| visited.insert(d.mod_id.clone()))
+            .collect();
+        if dependencies.is_empty() {
+            continue;
+        }
+
+        for (dependency, dep_mod) in lookup_dependency_mods(&settings.api_provider, &dependencies).await? {
+            let dep_mod = match dep_mod {
+                Some(m) if dependency.relation.is_installable() => Some(with_compatible_file(settings, m, &version.game_versions).await),
+                other => other,
+            };
+            if let Some(m) = dep_mod.as_ref().filter(|_| dependency.relation == DependencyRelation::Required) {
+                let nested = m.version.dependencies.iter()
+                    .filter(|d| matches!(d.relation, DependencyRelation::Required | DependencyRelation::Incompatible))
+                    .cloned()
+                    .collect();
+                queue.push_back((nested, Some(m.name.clone())));
+            }
+            resolved.push(ResolvedDependency { dependency, mod_data: dep_mod, required_by: required_by.clone() });
+        }
+    }
+    Ok(resolved)
+}
+
+/// Looks up the mods `dependencies` point at, in the same order. Dependencies whose mod no longer exists
+/// come back without one.
+async fn lookup_dependency_mods(
+    provider: &ApiProvider,
+    dependencies: &[UiDependency],
+) -> Result<Vec<(UiDependency, Option<UiMod>)>, String> {
+    let mods: Vec<UiMod> = match provider {
+        ApiProvider::CurseForge => {
+            let ids: Vec<u32> = dependencies.iter().filter_map(|d| d.mod_id.parse().ok()).collect();
+            if let Err(e) = curse_forge_api::load_classes().await {
+                eprintln!("CurseForge classes fetch failed: {}", e);
+            }
+            curse_forge_api::get_mods(&ids).await?
+                .iter()
+                .map(UiMod::from_curseforge_api)
+                .collect()
+        }
+        ApiProvider::Modtale => {
+            let mut mods = Vec::new();
+            for dependency in dependencies {
+                match mod_tale_api::get_mod(&dependency.mod_id).await {
+                    Ok(m) => mods.push(UiMod::from_modtale_api(&m)),
+                    Err(e) => eprintln!("ModTale fetch FAILED for {}: {}", dependency.mod_id, e),
+                }
+            }
+            mods
+        }
+    };
+
+    Ok(dependencies.iter()
+        .map(|d| (d.clone(), mods.iter().find(|m| m.id == d.mod_id).cloned()))
+        .collect())
+}
+
+/// `dep_mod` with `version` set to its newest file for one of `game_versions`. The latest file is kept
+/// when it already fits, when no game version is known, or when no file declares one of them.
+async fn with_compatible_file(settings: &AppSettings, mut dep_mod: UiMod, game_versions: &[String]) -> UiMod {
+    if game_versions.is_empty() || dep_mod.version.game_versions.iter().any(|g| game_versions.contains(g)) {
+        return dep_mod;
+    }
+
+    let mut best: Option<UiModVersion> = None;
+    for game_version in game_versions {
+        let filter = VersionFilter { game_version: Some(game_version.clone()), release_type: None };
+        match get_mod_versions_page_unified(settings, &dep_mod.id, &filter, 0, 1).await {
+            Ok(page) => {
+                let newest = page.versions.into_iter().next();
+                if let Some(candidate) = newest.filter(|c| best.as_ref().is_none_or(|b| c.upload_date > b.upload_date)) {
+                    best = Some(candidate);
+                }
+            }
+            Err(e) => eprintln!("Versions of dependency {} could not be listed: {}", dep_mod.name, e),
+        }
+    }
+
+    match best {
+        Some(version) => dep_mod.version = version,
+        None => eprintln!("{} has no file for {}; using its latest file", dep_mod.name, game_versions.join(", ")),
+    }
+    dep_mod
+}
+
+/// How many of an author's projects `get_author_mods_unified` lists at most.
+pub const MAX_AUTHOR_MODS: usize = 200;
+const AUTHOR_PAGE_SIZE: u32 = 50;
+
+/// Every project `author` has on `provider`, most recently updated first.
+///
+/// CurseForge searches by author id. Modtale has no author filter, so its search is run for the author's
+/// name and narrowed to projects they own.
+pub async fn get_author_mods_unified(provider: &ApiProvider, author: &UiAuthor) -> Result<Vec<UiMod>, String> {
+    let page_size = AUTHOR_PAGE_SIZE;
+    let mut mods = Vec::new();
+
+    match provider {
+        ApiProvider::CurseForge => {
+            let author_id = author.id.parse::<u32>()
+                .map_err(|_| format!("Invalid CurseForge author id: {}", author.id))?;
+            if let Err(e) = curse_forge_api::load_classes().await {
+                eprintln!("CurseForge classes fetch failed: {}", e);
+            }
+            loop {
+                let (page, pagination) = curse_forge_api::search_author_mods(author_id, mods.len() as u32, page_size).await?;
+                let done = page.is_empty() || pagination.is_none_or(|p| mods.len() + page.len() >= p.total_count as usize);
+                mods.extend(page.iter().map(UiMod::from_curseforge_api));
+                if done || mods.len() >= MAX_AUTHOR_MODS {
+                    break;
+                }
+            }
+        }
+        ApiProvider::Modtale => {
+            let mut offset = 0;
+            loop {
+                let (page, meta) = mod_tale_api::search_mods(author.name.clone(), "updated", offset, page_size, &[]).await?;
+                offset += page_size;
+                mods.extend(page.iter()
+                    .filter(|m| m.author.eq_ignore_ascii_case(&author.name))
+                    .map(UiMod::from_modtale_api));
+                let done = page.is_empty() || meta.is_none_or(|m| m.current_page + 1 >= m.last_page);
+                if done || mods.len() >= MAX_AUTHOR_MODS {
+                    break;
+                }
+            }
+        }
+    }
+
+    mods.truncate(MAX_AUTHOR_MODS);
+    Ok(mods)
+}
+
+/// Most entries the updates feed shows.
+pub const FEED_LENGTH: usize = 50;
+
+/// A project in the updates feed, with the followed author it came through.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedItem {
+    pub author: FollowedAuthor,
+    pub mod_data: UiMod,
+}
+
+/// The newest projects of every author followed on `provider`, most recent upload first. Authors that
+/// could not be loaded are reported in the second list instead of failing the whole feed.
+pub async fn get_updates_feed_unified(provider: &ApiProvider, followed: &[FollowedAuthor]) -> (Vec<FeedItem>, Vec<String>) {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+
+    for author in followed.iter().filter(|a| a.provider == *provider) {
+        match get_author_mods_unified(provider, &UiAuthor::from(author)).await {
+            Ok(mods) => items.extend(mods.into_iter().map(|mod_data| FeedItem { author: author.clone(), mod_data })),
+            Err(e) => errors.push(format!("{}: {}", author.name, e)),
+        }
+    }
+
+    items.sort_by(|a, b| b.mod_data.version.upload_date.cmp(&a.mod_data.version.upload_date));
+    // A project by several followed authors appears once.
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(item.mod_data.id.clone()));
+    items.truncate(FEED_LENGTH);
+    (items, errors)
+}
@@ -1,5 +0,0 @@
-pub mod curse_forge_api;
-pub mod mod_tale_api;
-pub mod settings;
-pub mod local_mods;
-pub mod ui_mod;
\ No newline at end of file
@@ -0,0 +1,438 @@
+use clap::{CommandFactory, Parser, Subcommand};
+use serde::Serialize;
+use serde_json::json;
+use std::path::PathBuf;
+use crate::api::capabilities::{capabilities, SortField};
+use crate::api::game_detect::validate_root;
+use crate::api::installer::{find_version, install_version};
+use crate::api::local_mods::remove_mod;
+use crate::api::settings::{AppSettings, InstalledModEntry};
+use crate::api::ui_mod::{channel_release_type, get_categories_unified, get_mod_details_unified, get_mod_versions_unified, search_mods_unified, SearchFilters, UiMod, VersionFilter};
+
+#[derive(Parser)]
+#[command(name = "HytaleModManager", version, about = "Manage Hytale mods from the command line. Run without arguments to open the app.")]
+struct Cli {
+    /// Print machine-readable JSON on stdout instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Instance to work on, by name. Defaults to the one selected in the app
+    #[arg(long, global = true)]
+    instance: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search the active provider
+    Search {
+        query: Vec<String>,
+        #[arg(long, default_value_t = 1)]
+        page: u32,
+        /// Only mods in this category (CurseForge id or Modtale tag); repeat for any of several
+        #[arg(long = "category")]
+        categories: Vec<String>,
+        /// Result order; `capabilities` lists what the active provider supports
+        #[arg(long)]
+        sort: Option<String>,
+        /// Results per page; `capabilities` lists what the active provider supports
+        #[arg(long)]
+        page_size: Option<u32>,
+    },
+    /// List the categories search can filter by
+    Categories,
+    /// Show the sort orders, filters and page sizes the active provider supports
+    Capabilities,
+    /// Show details of a mod
+    Info { mod_id: String },
+    /// List the published files of a mod
+    Versions {
+        mod_id: String,
+        /// Only files for this game version
+        #[arg(long)]
+        game_version: Option<String>,
+        /// Only files on this release channel: release, beta or alpha
+        #[arg(long)]
+        channel: Option<String>,
+    },
+    /// Install a mod, optionally a specific file of it
+    Install {
+        mod_id: String,
+        #[arg(long)]
+        file: Option<String>,
+    },
+    /// Remove an installed mod by id or file name
+    Remove { target: String },
+    /// Update one mod, or every managed mod with --all
+    Update {
+        mod_id: Option<String>,
+        #[arg(long, conflicts_with = "mod_id")]
+        all: bool,
+    },
+    /// List installed mods
+    List,
+    /// Reinstall managed mods missing from disk and reconcile the manifest with the folder
+    Sync,
+    /// Set the game folder of the instance, checked against its mods layout
+    SetRoot { path: PathBuf },
+}
+
+/// Exit codes: 0 success, 1 failure, 2 bad usage, 3 not found, 4 no game folder set.
+enum CliError {
+    Failed(String),
+    Usage(String),
+    NotFound(String),
+    NoGameFolder,
+}
+
+impl CliError {
+    fn code(&self) -> i32 {
+        match self {
+            CliError::Failed(_) => 1,
+            CliError::Usage(_) => 2,
+            CliError::NotFound(_) => 3,
+            CliError::NoGameFolder => 4,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            CliError::Failed(msg) | CliError::Usage(msg) | CliError::NotFound(msg) => msg.clone(),
+            CliError::NoGameFolder => "No game folder set for this instance; set one with `set-root <folder>`".to_string(),
+        }
+    }
+}
+
+/// Runs the CLI when the first argument is a subcommand (or --help / --version) and returns the
+/// process exit code. Returns `None` to let the GUI start.
+pub fn run_from_args() -> Option<i32> {
+    let first = std::env::args().nth(1)?;
+    let is_cli = matches!(first.as_str(), "-h" | "--help" | "-V" | "--version" | "--json" | "--instance" | "help")
+        || Cli::command().get_subcommands().any(|c| c.get_name() == first);
+    if !is_cli {
+        return None;
+    }
+
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            return Some(e.exit_code());
+        }
+    };
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+    let json = cli.json;
+    let result = runtime.block_on(run(cli));
+    crate::api::persist::flush();
+    let code = match result {
+        Ok(()) => 0,
+        Err(e) => {
+            if json {
+                println!("{}", json!({ "error": e.message(), "code": e.code() }));
+            } else {
+                eprintln!("error: {}", e.message());
+            }
+            e.code()
+        }
+    };
+    Some(code)
+}
+
+async fn run(cli: Cli) -> Result<(), CliError> {
+    let mut settings = AppSettings::load();
+    if let Some(failure) = settings.recovery() {
+        let backup = failure.backup.as_ref().map(|p| format!(" (backed up to {})", p.display())).unwrap_or_default();
+        return Err(CliError::Failed(format!("{}{}. Fix or remove settings.json, or open the app to recover.", failure.error, backup)));
+    }
+    let selected = settings.active_instance;
+
+    if let Some(name) = &cli.instance {
+        let index = settings.instances.iter()
+            .position(|i| i.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| CliError::Usage(format!("No instance named \"{}\"", name)))?;
+        settings.select_instance(index);
+    }
+
+    let result = run_command(cli.command, cli.json, &mut settings).await;
+
+    // Working on another instance must not change which one the app has selected.
+    if settings.active_instance != selected {
+        settings.select_instance(selected);
+        let _ = settings.save();
+    }
+
+    result
+}
+
+async fn run_command(command: Command, json: bool, settings: &mut AppSettings) -> Result<(), CliError> {
+    match command {
+        Command::Search { query, page, categories, sort, page_size } => {
+            let caps = capabilities(&settings.api_provider);
+            let sort = match sort {
+                Some(slug) => SortField::from_slug(&slug)
+                    .filter(|s| caps.supports_sort(*s))
+                    .ok_or_else(|| CliError::Usage(format!(
+                        "{} cannot sort by \"{}\"; supported: {}",
+                        settings.api_provider.label(),
+                        slug,
+                        caps.sort_fields.iter().map(|s| s.slug()).collect::<Vec<_>>().join(", "),
+                    )))?,
+                None => caps.default_sort(),
+            };
+            let page_size = page_size.unwrap_or(caps.default_page_size());
+            if !caps.page_sizes.contains(&page_size) {
+                return Err(CliError::Usage(format!("Page size must be one of {:?}", caps.page_sizes)));
+            }
+
+            let offset = page.saturating_sub(1) * page_size;
+            let filters = SearchFilters { categories };
+            let (mods, total_pages) = search_mods_unified(settings, sort, query.join(" "), offset, page_size, &filters).await
+                .map_err(CliError::Failed)?;
+
+            if json {
+                print_json(&json!({ "page": page, "total_pages": total_pages, "mods": mods }));
+            } else {
+                for m in &mods {
+                    println!("{:<10} {}  ({}, {} downloads)", m.id, m.name, m.version.display_name, m.download_count);
+                }
+                println!("Page {} of {}", page, total_pages);
+            }
+            Ok(())
+        }
+        Command::Capabilities => {
+            let caps = capabilities(&settings.api_provider);
+            if json {
+                print_json(caps);
+            } else {
+                let sorts: Vec<&str> = caps.sort_fields.iter().map(|s| s.slug()).collect();
+                println!("Provider:    {}", settings.api_provider.label());
+                println!("Sort:        {}", sorts.join(", "));
+                println!("Page sizes:  {:?}", caps.page_sizes);
+                println!("Categories:  {}", if caps.category_filter { "yes" } else { "no" });
+                println!("Featured:    {}", if caps.featured { "yes" } else { "no" });
+            }
+            Ok(())
+        }
+        Command::Categories => {
+            let categories = get_categories_unified(&settings.api_provider).await
+                .map_err(CliError::Failed)?;
+
+            if json {
+                print_json(&categories);
+            } else {
+                for c in &categories {
+                    let group = if c.group.is_empty() { String::new() } else { format!("  [{}]", c.group) };
+                    println!("{:<10} {}{}", c.id, c.name, group);
+                }
+            }
+            Ok(())
+        }
+        Command::Info { mod_id } => {
+            let m = fetch_mod(settings, &mod_id).await?;
+            if json {
+                print_json(&m);
+            } else {
+                println!("{} ({})", m.name, m.id);
+                println!("By {}", m.authors);
+                println!("Type: {}", m.content_type.label());
+                println!("Latest: {} [{}]", m.version.display_name, m.version.file_id);
+                println!("Downloads: {}", m.download_count);
+                println!("{}", m.website_url);
+                println!();
+                println!("{}", m.summary);
+            }
+            Ok(())
+        }
+        Command::Versions { mod_id, game_version, channel } => {
+            let release_type = match channel {
+                Some(name) => Some(channel_release_type(&name)
+                    .ok_or_else(|| CliError::Usage(format!("Unknown channel \"{}\"; use release, beta or alpha", name)))?),
+                None => None,
+            };
+            let filter = VersionFilter { game_version, release_type };
+            let versions = get_mod_versions_unified(settings, &mod_id, &filter).await.map_err(CliError::Failed)?;
+            if json {
+                print_json(&versions);
+            } else {
+                for v in &versions {
+                    println!("{:<10} {}  {}  {}", v.file_id, v.display_name, v.game_versions.join(","), v.upload_date);
+                }
+            }
+            Ok(())
+        }
+        Command::Install { mod_id, file } => {
+            require_game_folder(settings)?;
+            let m = fetch_mod(settings, &mod_id).await?;
+            let version = match file {
+                Some(file_id) => find_version(settings, &mod_id, &file_id).await.map_err(CliError::NotFound)?,
+                None => m.version.clone(),
+            };
+
+            install_version(settings, &m, &version).await.map_err(CliError::Failed)?;
+
+            if json {
+                print_json(&json!({ "installed": { "mod_id": m.id, "name": m.name, "file_id": version.file_id, "file_name": version.file_name } }));
+            } else {
+                println!("Installed {} {}", m.name, version.display_name);
+            }
+            Ok(())
+        }
+        Command::Remove { target } => {
+            require_game_folder(settings)?;
+            let file_name = settings.installed_mods().iter()
+                .find(|(file, entry)| **file == target || (entry.mod_id != "0" && entry.mod_id == target))
+                .map(|(file, _)| file.clone())
+                .ok_or_else(|| CliError::NotFound(format!("{} is not installed", target)))?;
+
+            remove_mod(&file_name, settings).map_err(CliError::Failed)?;
+
+            if json {
+                print_json(&json!({ "removed": file_name }));
+            } else {
+                println!("Removed {}", file_name);
+            }
+            Ok(())
+        }
+        Command::Update { mod_id, all } => {
+            require_game_folder(settings)?;
+            let targets: Vec<InstalledModEntry> = match (&mod_id, all) {
+                (Some(id), _) => {
+                    let entry = settings.installed_mods().values()
+                        .find(|e| e.mod_id == *id)
+                        .cloned()
+                        .ok_or_else(|| CliError::NotFound(format!("{} is not installed", id)))?;
+                    vec![entry]
+                }
+                (None, true) => settings.installed_mods().values()
+                    .filter(|e| e.mod_id != "0")
+                    .cloned()
+                    .collect(),
+                (None, false) => return Err(CliError::Usage("Give a mod id or pass --all".to_string())),
+            };
+
+            let mut updated = Vec::new();
+            let mut failed = Vec::new();
+            for entry in targets {
+                if entry.api_provider != settings.api_provider {
+                    failed.push(json!({ "mod_id": entry.mod_id, "error": format!("Managed by {:?}, switch provider to update it", entry.api_provider) }));
+                    continue;
+                }
+                let Some(m) = get_mod_details_unified(&entry.api_provider, &entry.mod_id).await else {
+                    failed.push(json!({ "mod_id": entry.mod_id, "error": "Mod not found" }));
+                    continue;
+                };
+                if m.version.file_id.is_empty() || m.version.file_id == entry.file_id {
+                    continue;
+                }
+                match install_version(settings, &m, &m.version).await {
+                    Ok(_) => updated.push(json!({ "mod_id": m.id, "name": m.name, "from": entry.version_name, "to": m.version.display_name })),
+                    Err(e) => failed.push(json!({ "mod_id": m.id, "error": e })),
+                }
+            }
+
+            report("Updated", &updated, &failed, json);
+            if failed.is_empty() { Ok(()) } else { Err(CliError::Failed(format!("{} update(s) failed", failed.len()))) }
+        }
+        Command::List => {
+            let mut entries: Vec<(&String, &InstalledModEntry)> = settings.installed_mods().iter().collect();
+            entries.sort_by_key(|(_, e)| e.mod_name.to_lowercase());
+
+            if json {
+                let list: Vec<_> = entries.iter()
+                    .map(|(file, e)| json!({ "file_name": file, "entry": e }))
+                    .collect();
+                print_json(&list);
+            } else {
+                for (file, e) in entries {
+                    let id = if e.mod_id == "0" { "local" } else { e.mod_id.as_str() };
+                    println!("{:<10} {}  {}  [{}]  {}", id, e.mod_name, e.version_name, e.content_type.label(), file);
+                }
+            }
+            Ok(())
+        }
+        Command::Sync => {
+            require_game_folder(settings)?;
+            let missing: Vec<InstalledModEntry> = settings.installed_mods().iter()
+                .filter(|(file, e)| {
+                    e.mod_id != "0" && settings.get_content_dir(e.content_type).is_some_and(|dir| !dir.join(file).exists())
+                })
+                .map(|(_, e)| e.clone())
+                .collect();
+
+            let mut restored = Vec::new();
+            let mut failed = Vec::new();
+            for entry in missing {
+                if entry.api_provider != settings.api_provider {
+                    failed.push(json!({ "mod_id": entry.mod_id, "error": format!("Managed by {:?}, switch provider to restore it", entry.api_provider) }));
+                    continue;
+                }
+                let result = match get_mod_details_unified(&entry.api_provider, &entry.mod_id).await {
+                    Some(m) => match find_version(settings, &entry.mod_id, &entry.file_id).await {
+                        Ok(version) => install_version(settings, &m, &version).await.map(|_| m.name),
+                        Err(e) => Err(e),
+                    },
+                    None => Err("Mod not found".to_string()),
+                };
+                match result {
+                    Ok(name) => restored.push(json!({ "mod_id": entry.mod_id, "name": name, "version": entry.version_name })),
+                    Err(e) => failed.push(json!({ "mod_id": entry.mod_id, "error": e })),
+                }
+            }
+
+            settings.adopt_untracked_files();
+            settings.prune_manifest();
+
+            report("Restored", &restored, &failed, json);
+            if failed.is_empty() { Ok(()) } else { Err(CliError::Failed(format!("{} mod(s) could not be restored", failed.len()))) }
+        }
+        Command::SetRoot { path } => {
+            let root = std::path::absolute(&path).unwrap_or(path);
+            validate_root(&root, &settings.active_instance().layout).map_err(CliError::Usage)?;
+
+            settings.set_game_folder(Some(root.clone()));
+            settings.save().map_err(|e| CliError::Failed(format!("Failed to save settings: {}", e)))?;
+
+            if json {
+                print_json(&json!({ "instance": settings.active_instance().name, "root": root }));
+            } else {
+                println!("Game folder of {} set to {}", settings.active_instance().name, root.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn fetch_mod(settings: &AppSettings, mod_id: &str) -> Result<UiMod, CliError> {
+    get_mod_details_unified(&settings.api_provider, mod_id).await
+        .ok_or_else(|| CliError::NotFound(format!("Mod {} not found", mod_id)))
+}
+
+fn require_game_folder(settings: &AppSettings) -> Result<(), CliError> {
+    settings.get_game_folder().map(|_| ()).ok_or(CliError::NoGameFolder)
+}
+
+fn report(verb: &str, done: &[serde_json::Value], failed: &[serde_json::Value], json: bool) {
+    if json {
+        let mut summary = serde_json::Map::new();
+        summary.insert(verb.to_lowercase(), json!(done));
+        summary.insert("failed".to_string(), json!(failed));
+        print_json(&summary);
+        return;
+    }
+    for item in done {
+        println!("{} {}", verb, item["name"].as_str().unwrap_or_default());
+    }
+    for item in failed {
+        eprintln!("Failed {}: {}", item["mod_id"].as_str().unwrap_or_default(), item["error"].as_str().unwrap_or_default());
+    }
+    if done.is_empty() && failed.is_empty() {
+        println!("Nothing to do");
+    }
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+}
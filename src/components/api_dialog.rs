@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use crate::api::settings::{AppSettings, ApiProvider};
+use crate::api::secrets::{api_key, env_var, key_source, KeySource};
+use crate::api::settings::{store_api_key, AppSettings, ApiProvider, KeyStatus};
+use crate::api::ui_mod::check_key_unified;
 use crate::components::drop_down::ThemeDropdown;
 
 #[component]
@@ -9,13 +11,59 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
     let current_settings = app_settings.read();
     let mut selected_provider = use_signal(|| current_settings.api_provider.clone());
     let mut input_val = use_signal(|| current_settings.api_key.clone().unwrap_or_default());
+    let mut error_msg = use_signal(|| Option::<String>::None);
+    let mut status = use_signal(|| Option::<KeyStatus>::None);
+    let mut is_checking = use_signal(|| false);
 
+    // The keyring can be slow or ask for a password, so stored keys are looked up off the UI thread.
+    let stored = use_resource(move || async move {
+        let provider = selected_provider();
+        tokio::task::spawn_blocking(move || (api_key(&provider), key_source(&provider)))
+            .await
+            .unwrap_or_default()
+    });
+    // Keys are stored per provider, so show the one that belongs to the selected provider.
+    use_effect(move || {
+        if let Some((key, _)) = &*stored.read() {
+            input_val.set(key.clone().unwrap_or_default());
+        }
+    });
+    let source = stored.read().as_ref().and_then(|(_, source)| *source);
+    let from_env = source == Some(KeySource::Environment);
+
+    // Checks the key live first. Refused keys are not saved; keys that can't be confirmed yet are saved
+    // with an explanation, and the dialog stays open so it can be read.
     let handle_save = move |_| {
-        let key = input_val();
         let provider = selected_provider();
-        app_settings.write().change_api(provider, key);
-        let _ = app_settings.read().save();
-        on_close.call(());
+        let key = input_val();
+        is_checking.set(true);
+        error_msg.set(None);
+        status.set(None);
+
+        spawn(async move {
+            let result = check_key_unified(&provider, &key).await;
+            is_checking.set(false);
+
+            if !matches!(result, KeyStatus::Invalid(_)) {
+                // Storing waits on the keyring, so it runs off the UI thread and without holding the settings.
+                let stored_provider = provider.clone();
+                let stored = tokio::task::spawn_blocking(move || store_api_key(&stored_provider, &key))
+                    .await
+                    .unwrap_or_else(|e| Err(format!("Failed to store the key: {}", e)));
+                if let Err(e) = stored {
+                    error_msg.set(Some(e));
+                    return;
+                }
+                app_settings.write().select_api(provider);
+                let _ = app_settings.read().save_now();
+            }
+
+            if result == KeyStatus::Valid {
+                on_close.call(());
+            } else {
+                status.set(Some(result));
+            }
+        });
     };
 
     rsx! {
@@ -28,7 +76,7 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
                 onclick: |e| e.stop_propagation(),
 
                 h3 { style: "margin: 0; color: var(--text-primary);", "Configure API" }
-                p { style: "margin: 0; font-size: 10px; color: var(--text-secondary);", "The API key may take a few Hours to start working after generating it on the website" }
+                p { style: "margin: 0; font-size: 10px; color: var(--text-secondary);", "The key is checked against the provider when you save." }
 
                 div { style: "display: flex; flex-direction: column; gap: 5px;",
                     label { style: "font-size: 12px; color: var(--text-secondary);", "Provider" }
@@ -38,11 +86,15 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
                         placeholder: "Api Provider",
                         default_index: Some(selected_provider().clone() as usize),
                         on_select: move |idx| {
-                            match idx {
-                                0 => selected_provider.set(ApiProvider::Modtale),
-                                1 => selected_provider.set(ApiProvider::CurseForge),
-                                _ => {}
-                            }
+                            let provider = match idx {
+                                0 => ApiProvider::Modtale,
+                                1 => ApiProvider::CurseForge,
+                                _ => return,
+                            };
+                            input_val.set(String::new());
+                            selected_provider.set(provider);
+                            error_msg.set(None);
+                            status.set(None);
                         },
                     }
                 }
@@ -53,8 +105,12 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
                         r#type: "password",
                         style: "padding: 8px; border-radius: 5px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border-color);",
                         placeholder: "Paste API Key here...",
+                        disabled: from_env,
                         value: "{input_val}",
-                        oninput: move |e| input_val.set(e.value())
+                        oninput: move |e| {
+                            input_val.set(e.value());
+                            status.set(None);
+                        }
                     }
                     div { style: "font-size: 10px; color: var(--text-secondary); margin-top: 2px;",
                         if selected_provider() == ApiProvider::CurseForge {
@@ -63,6 +119,32 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
                             "ModTale key (Optional for public access)."
                         }
                     }
+                    div { style: "font-size: 10px; color: var(--text-secondary);",
+                        match source {
+                            Some(KeySource::Environment) => rsx! { "Set by the {env_var(&selected_provider())} environment variable." },
+                            Some(other) => rsx! { "Stored in the {other.label()}, not in settings.json." },
+                            None => rsx! { "Keys are kept in the system keyring, not in settings.json." },
+                        }
+                    }
+                }
+
+                match status() {
+                    Some(KeyStatus::Invalid(reason)) => rsx! {
+                        span { style: "font-size: 12px; color: var(--danger);", "Invalid key: {reason}" }
+                    },
+                    Some(KeyStatus::NotYetActive) => rsx! {
+                        span { style: "font-size: 12px; color: var(--warning);",
+                            "Saved, but CurseForge does not accept this key yet. New keys can take a few hours to activate; if it still fails tomorrow, generate a new one."
+                        }
+                    },
+                    Some(KeyStatus::Unverified(reason)) => rsx! {
+                        span { style: "font-size: 12px; color: var(--warning);", "Saved without checking, the provider could not be reached: {reason}" }
+                    },
+                    Some(KeyStatus::Valid) | None => rsx! {},
+                }
+
+                if let Some(err) = error_msg() {
+                    span { style: "font-size: 12px; color: var(--danger);", "{err}" }
                 }
 
                 div { style: "display: flex; gap: 10px; margin-top: 10px;",
@@ -70,13 +152,15 @@ pub fn ApiDialog(on_close: EventHandler<()>) -> Element {
                         class: "btn btn-ghost",
                         style: "flex: 1; background-color: var(--bg-quaternary);",
                         onclick: move |_| on_close.call(()),
-                        "Cancel"
+                        if matches!(status(), Some(KeyStatus::NotYetActive | KeyStatus::Unverified(_))) { "Close" } else { "Cancel" }
                     }
                     button {
                         class: "btn btn-brand",
                         style: "flex: 1;",
+                        // Saving before the stored key is shown would replace it with an empty one.
+                        disabled: is_checking() || !stored.finished(),
                         onclick: handle_save,
-                        "Save & Apply"
+                        if is_checking() { "Checking..." } else { "Save & Apply" }
                     }
                 }
             }
@@ -0,0 +1,118 @@
+use dioxus::prelude::*;
+use crate::api::settings::{AppSettings, FollowedAuthor};
+use crate::api::ui_mod::{get_author_mods_unified, UiAuthor, UiMod, MAX_AUTHOR_MODS};
+use crate::components::mod_card::ModCard;
+use crate::state::connectivity::{Connectivity, OFFLINE_REASON};
+
+/// Every project of one author on the active provider, with install states, and a Follow toggle that
+/// puts their new releases in the Following feed.
+#[component]
+pub fn AuthorDialog(
+    author: UiAuthor,
+    on_open_info: EventHandler<UiMod>,
+    on_open_author: EventHandler<UiAuthor>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let connectivity = use_context::<Connectivity>();
+
+    let projects = use_resource(use_reactive(&author, move |author| async move {
+        if !connectivity.is_online() {
+            return Err(OFFLINE_REASON.to_string());
+        }
+        let provider = app_settings.read().api_provider.clone();
+        get_author_mods_unified(&provider, &author).await
+    }));
+
+    let provider = app_settings.read().api_provider.clone();
+    let following = app_settings.read().is_following(&provider, &author.id);
+
+    let toggle_follow = {
+        let author = author.clone();
+        move |_| {
+            let provider = app_settings.read().api_provider.clone();
+            if following {
+                app_settings.write().unfollow_author(&provider, &author.id);
+                return;
+            }
+            // Only releases after this moment count as new.
+            let seen_until = match &*projects.read() {
+                Some(Ok(mods)) => mods.iter().map(|m| m.version.upload_date.clone()).max().unwrap_or_default(),
+                _ => String::new(),
+            };
+            app_settings.write().follow_author(FollowedAuthor {
+                provider,
+                id: author.id.clone(),
+                name: author.name.clone(),
+                url: author.url.clone(),
+                seen_until,
+            });
+        }
+    };
+
+    let url = author.url.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.8); display: flex; align-items: center; justify-content: center; z-index: 99; backdrop-filter: blur(2px);",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background-color: var(--bg-secondary); width: 75%; height: 80%; max-width: 1100px; display: flex; flex-direction: column; border-radius: 12px; border: 1px solid var(--text-secondary); box-shadow: 0 10px 25px rgba(0,0,0,0.5); color: var(--text-primary); overflow: hidden;",
+                onclick: |e| e.stop_propagation(),
+
+                div { style: "display: flex; gap: 15px; align-items: center; background-color: var(--bg-tertiary); padding: 20px 30px;",
+                    div { style: "flex: 1;",
+                        h2 { style: "margin: 0; font-size: 22px;", "{author.name}" }
+                        div { style: "font-size: 13px; color: var(--text-secondary); margin-top: 4px;",
+                            match &*projects.read() {
+                                Some(Ok(mods)) if mods.len() >= MAX_AUTHOR_MODS => rsx! { "{mods.len()}+ projects on {provider.label()}" },
+                                Some(Ok(mods)) => rsx! { "{mods.len()} projects on {provider.label()}" },
+                                _ => rsx! { "Projects on {provider.label()}" },
+                            }
+                        }
+                    }
+                    button {
+                        class: if following { "btn btn-neutral" } else { "btn btn-brand" },
+                        // Following records the newest release as seen, which needs the project list.
+                        disabled: !following && !connectivity.is_online(),
+                        title: if !following && !connectivity.is_online() { OFFLINE_REASON },
+                        onclick: toggle_follow,
+                        if following { "✔ Following" } else { "+ Follow" }
+                    }
+                    if !url.is_empty() {
+                        button {
+                            class: "btn btn-outline",
+                            onclick: move |_| {
+                                if let Err(e) = open::that(&url) {
+                                    eprintln!("Could not open browser: {}", e);
+                                }
+                            },
+                            "View Website ↗"
+                        }
+                    }
+                    button { class: "btn btn-ghost", style: "font-size: 20px;", onclick: move |_| on_close.call(()), "✕" }
+                }
+
+                div { style: "flex: 1; overflow-y: auto; padding: 20px 30px; display: flex; flex-direction: column; gap: 10px;",
+                    match &*projects.read() {
+                        None => rsx! {
+                            div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "Loading projects..." }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { style: "text-align: center; color: var(--danger); margin-top: 50px;", "Could not load projects: {e}" }
+                        },
+                        Some(Ok(mods)) if mods.is_empty() => rsx! {
+                            div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "No projects found." }
+                        },
+                        Some(Ok(mods)) => rsx! {
+                            for m in mods.iter().cloned() {
+                                ModCard { key: "{m.id}", mod_data: m, onclick: on_open_info, on_open_author }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use dioxus::prelude::*;
+use crate::api::image_cache::{image_src, ImageSize};
+use crate::api::ui_mod::UiCategory;
+
+/// Multi-select dropdown of provider categories. Results match any selected category.
+#[component]
+pub fn CategoryFilter(
+    categories: Vec<UiCategory>,
+    selected: Vec<String>,
+    /// Most categories the provider accepts at once, if it has a limit.
+    max: Option<usize>,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let mut is_open = use_signal(|| false);
+
+    let label = match selected.len() {
+        0 => "All Categories".to_string(),
+        1 => categories.iter()
+            .find(|c| c.id == selected[0])
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| "1 Category".to_string()),
+        n => format!("{} Categories", n),
+    };
+    let arrow = if is_open() { "▲" } else { "▼" };
+    let display_mode = if is_open() { "flex" } else { "none" };
+    let is_full = max.is_some_and(|max| selected.len() >= max);
+
+    rsx! {
+        div {
+            style: "position: relative;",
+
+            button {
+                class: "btn",
+                style: "width: 100%; min-height: 40px; background-color: var(--input-bg); border: 1px solid var(--border-color); color: var(--text-primary); justify-content: space-between; display: flex; align-items: center; padding: 10px;",
+                disabled: categories.is_empty(),
+                onclick: move |_| is_open.set(!is_open()),
+                span { style: "overflow: hidden; text-overflow: ellipsis; white-space: nowrap;", "{label}" }
+                span { style: "font-size: 10px; margin-left: 10px;", "{arrow}" }
+            }
+
+            div {
+                style: "display: {display_mode}; flex-direction: column; position: absolute; top: 110%; left: 0; min-width: 100%; width: max-content; max-width: 320px; max-height: 360px; z-index: 10; background-color: var(--input-bg); border: 1px solid var(--border-color); border-radius: 4px;",
+
+                div { style: "display: flex; justify-content: space-between; align-items: center; padding: 8px 10px; border-bottom: 1px solid var(--border-color); font-size: 11px; color: var(--text-secondary);",
+                    span {
+                        if let Some(max) = max.filter(|_| is_full) { "Up to {max} at once" } else { "Match any selected" }
+                    }
+                    button {
+                        class: "btn btn-ghost",
+                        style: "padding: 2px 8px; font-size: 11px;",
+                        disabled: selected.is_empty(),
+                        onclick: move |_| on_change.call(Vec::new()),
+                        "Clear"
+                    }
+                }
+
+                div { style: "overflow-y: auto;",
+                    for (i, category) in categories.iter().enumerate() {
+                        if !category.group.is_empty() && (i == 0 || categories[i - 1].group != category.group) {
+                            div {
+                                key: "group-{category.group}",
+                                style: "padding: 8px 10px 4px; font-size: 10px; font-weight: bold; text-transform: uppercase; color: var(--text-tertiary);",
+                                "{category.group}"
+                            }
+                        }
+                        {
+                            let is_selected = selected.contains(&category.id);
+                            let id = category.id.clone();
+                            let current = selected.clone();
+                            rsx! {
+                                label {
+                                    key: "{category.id}",
+                                    class: "dropdown-item",
+                                    style: "display: flex; gap: 8px; align-items: center; padding: 6px 10px; cursor: pointer; color: var(--text-secondary); font-size: 13px;",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: is_selected,
+                                        disabled: is_full && !is_selected,
+                                        onchange: move |_| {
+                                            let mut next = current.clone();
+                                            if let Some(pos) = next.iter().position(|s| *s == id) {
+                                                next.remove(pos);
+                                            } else {
+                                                next.push(id.clone());
+                                            }
+                                            on_change.call(next);
+                                        },
+                                    }
+                                    if !category.icon.is_empty() {
+                                        img { src: image_src(&category.icon, ImageSize::Icon), style: "width: 16px; height: 16px; object-fit: contain;" }
+                                    }
+                                    span { "{category.name}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use crate::api::settings::AppSettings;
+use crate::api::ui_mod::{get_changelog_between_unified, UiMod, UiModVersion, MAX_CHANGELOG_VERSIONS};
+
+/// Shown before an update: every changelog between the installed file and `target`, newest first.
+#[component]
+pub fn ChangelogDialog(
+    mod_data: UiMod,
+    installed_file_id: String,
+    target: UiModVersion,
+    on_confirm: EventHandler<UiModVersion>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let app_settings = use_context::<Signal<AppSettings>>();
+
+    let changelogs = use_resource(use_reactive((&mod_data.id, &installed_file_id, &target), move |(mod_id, installed, target)| async move {
+        let settings = app_settings.read().clone();
+        get_changelog_between_unified(&settings, &mod_id, Some(&installed), &target).await
+    }));
+
+    let target_confirm = target.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 100;",
+            onclick: move |e| {
+                e.stop_propagation();
+                on_close.call(());
+            },
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 640px; max-height: 80%; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--bg-quaternary); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+                onclick: |e| e.stop_propagation(),
+
+                h3 { style: "margin: 0; color: var(--text-primary);", "Update {mod_data.name} to {target.display_name}" }
+
+                div { style: "flex: 1; overflow-y: auto; display: flex; flex-direction: column; gap: 15px; padding-right: 5px;",
+                    match &*changelogs.read() {
+                        None => rsx! { span { style: "font-size: 12px; color: var(--text-secondary);", "Loading changelogs..." } },
+                        Some(Err(e)) => rsx! { span { style: "font-size: 12px; color: var(--danger);", "Could not load changelogs: {e}" } },
+                        Some(Ok(entries)) => rsx! {
+                            if entries.len() >= MAX_CHANGELOG_VERSIONS {
+                                span { style: "font-size: 11px; color: var(--text-secondary);", "Showing the {MAX_CHANGELOG_VERSIONS} newest versions." }
+                            }
+                            for (version, html) in entries.iter() {
+                                div { key: "{version.file_id}", style: "display: flex; flex-direction: column; gap: 6px;",
+                                    span { style: "font-weight: bold; color: var(--text-primary);", "{version.display_name}" }
+                                    if html.trim().is_empty() {
+                                        span { style: "font-size: 12px; color: var(--text-secondary);", "No changelog provided." }
+                                    } else {
+                                        div { class: "rich-text", dangerous_inner_html: "{html}" }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+
+                div { style: "display: flex; gap: 10px;",
+                    button {
+                        class: "btn btn-ghost",
+                        style: "flex: 1; background-color: var(--bg-quaternary);",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        class: "btn btn-warning",
+                        style: "flex: 1;",
+                        onclick: move |_| on_confirm.call(target_confirm.clone()),
+                        "Update"
+                    }
+                }
+            }
+        }
+    }
+}
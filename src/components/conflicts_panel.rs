@@ -0,0 +1,82 @@
+use dioxus::prelude::*;
+use crate::api::conflicts::{resolve_conflict, ConflictKind, ModConflict};
+use crate::api::settings::AppSettings;
+
+#[component]
+pub fn ConflictsPanel(conflicts: Vec<ModConflict>, on_resolved: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut error_msg = use_signal(|| Option::<String>::None);
+
+    let mut keep_file = move |conflict: ModConflict, keep: String| {
+        let result = resolve_conflict(&conflict, &keep, &mut app_settings.write());
+        match result {
+            Ok(_) => error_msg.set(None),
+            Err(e) => error_msg.set(Some(format!("Failed to resolve conflict: {}", e))),
+        }
+        on_resolved.call(());
+    };
+
+    rsx! {
+        div { style: "display: flex; flex-direction: column; gap: 8px; margin-bottom: 20px; padding: 12px; border-radius: 8px; border: 1px solid var(--warning); background-color: var(--bg-tertiary);",
+            span { style: "color: var(--warning); font-weight: bold; font-size: 14px;",
+                "⚠ {conflicts.len()} mod(s) are present more than once. The game will load every copy."
+            }
+            if let Some(err) = error_msg() {
+                span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+            }
+
+            for conflict in conflicts.into_iter() {
+                {
+                    let kind_label = match conflict.kind {
+                        ConflictKind::Duplicate => "Duplicate copies",
+                        ConflictKind::VersionMismatch => "Different versions",
+                    };
+                    let newest = conflict.newest().map(|f| f.file_name.clone());
+                    let managed = conflict.managed().map(|f| f.file_name.clone());
+                    let conflict_for_newest = conflict.clone();
+                    let conflict_for_managed = conflict.clone();
+
+                    rsx! {
+                        div {
+                            key: "{conflict.name}",
+                            style: "display: flex; flex-direction: column; gap: 6px; background-color: var(--bg-secondary); padding: 10px; border-radius: 6px;",
+                            div { style: "display: flex; align-items: center; gap: 8px;",
+                                span { style: "font-weight: bold; color: var(--text-primary);", "{conflict.name}" }
+                                span { style: "font-size: 10px; color: var(--warning); padding: 2px 6px; border-radius: 4px; border: 1px solid var(--warning);", "{kind_label}" }
+                                div { style: "flex: 1;" }
+                                if let Some(newest) = newest {
+                                    button {
+                                        class: "btn btn-warning",
+                                        style: "padding: 5px 12px; font-size: 12px;",
+                                        title: "Keep {newest} and delete the rest",
+                                        onclick: move |_| keep_file(conflict_for_newest.clone(), newest.clone()),
+                                        "Keep newest"
+                                    }
+                                }
+                                if let Some(managed) = managed {
+                                    button {
+                                        class: "btn btn-secondary",
+                                        style: "padding: 5px 12px; font-size: 12px;",
+                                        title: "Keep {managed} and delete the rest",
+                                        onclick: move |_| keep_file(conflict_for_managed.clone(), managed.clone()),
+                                        "Keep managed"
+                                    }
+                                }
+                            }
+                            for file in conflict.files.iter() {
+                                div { style: "font-size: 12px; color: var(--text-secondary); display: flex; gap: 10px;",
+                                    span { style: "font-family: monospace;", "{file.file_name}" }
+                                    span { "•" }
+                                    span { "{file.version}" }
+                                    if file.managed {
+                                        span { style: "color: var(--brand-primary);", "managed" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use crate::api::deep_link::{parse_deep_link, DeepLink};
+use crate::api::image_cache::{image_src, ImageSize};
+use crate::api::installer::{download_version, resolve_mod};
+use crate::api::modpack::{fetch_pack, ModPack};
+use crate::api::settings::{ApiProvider, AppSettings};
+use crate::state::mod_store::ModStore;
+
+/// Confirmation popup for a `hytalemm://` link. Nothing is installed until the user confirms.
+#[component]
+pub fn DeepLinkDialog(link: String, on_close: EventHandler<()>) -> Element {
+    let parsed = parse_deep_link(&link);
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 99;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 480px; max-height: 85%; overflow-y: auto; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--border-color); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+                onclick: |e| e.stop_propagation(),
+
+                match parsed {
+                    Ok(DeepLink::Install { provider, mod_id, file_id }) => rsx! {
+                        InstallLink { provider, mod_id, file_id, on_close }
+                    },
+                    Ok(DeepLink::ImportPack { url }) => rsx! {
+                        ImportPackLink { url, on_close }
+                    },
+                    Err(e) => rsx! {
+                        h3 { style: "margin: 0; color: var(--text-primary);", "Can't Open Link" }
+                        span { style: "font-size: 12px; color: var(--danger); word-break: break-all;", "{e}" }
+                        button { class: "btn btn-ghost", style: "background-color: var(--bg-quaternary);", onclick: move |_| on_close.call(()), "Close" }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn InstallLink(provider: ApiProvider, mod_id: String, file_id: Option<String>, on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut mod_store = use_context::<Signal<ModStore>>();
+    let mut is_working = use_signal(|| false);
+    let mut error_msg = use_signal(|| Option::<String>::None);
+
+    let resolve_provider = provider.clone();
+    let target = use_resource(move || {
+        let (provider, mod_id, file_id) = (resolve_provider.clone(), mod_id.clone(), file_id.clone());
+        async move {
+            let settings = app_settings.peek().clone();
+            resolve_mod(&settings, &provider, &mod_id, file_id.as_deref()).await
+        }
+    });
+
+    let handle_install = move |_| {
+        let Some(Ok((mod_data, version))) = target() else { return };
+        if app_settings.read().get_game_folder().is_none() {
+            error_msg.set(Some("No Game Folder Set".to_string()));
+            return;
+        }
+
+        let provider = provider.clone();
+        let instance = app_settings.read().active_instance;
+        is_working.set(true);
+        error_msg.set(None);
+
+        spawn(async move {
+            let settings = app_settings.read().for_provider(&provider);
+            let result = match download_version(&settings, &mod_data, &version).await {
+                Ok(downloaded) => downloaded.install(instance, &mut app_settings.write()),
+                Err(e) => Err(e),
+            };
+
+            is_working.set(false);
+            match result {
+                Ok(_) => {
+                    let mut store = mod_store.write();
+                    store.invalidate();
+                    store.trigger_refresh();
+                    on_close.call(());
+                }
+                Err(e) => error_msg.set(Some(e)),
+            }
+        });
+    };
+
+    rsx! {
+        h3 { style: "margin: 0; color: var(--text-primary);", "Install Mod?" }
+
+        match target() {
+            None => rsx! {
+                div { style: "display: flex; gap: 10px; align-items: center; color: var(--text-secondary);",
+                    div { class: "spinner" }
+                    span { "Looking up mod..." }
+                }
+            },
+            Some(Err(e)) => rsx! {
+                span { style: "font-size: 12px; color: var(--danger);", "{e}" }
+            },
+            Some(Ok((mod_data, version))) => rsx! {
+                div { style: "display: flex; gap: 15px; align-items: center;",
+                    if !mod_data.icon.is_empty() {
+                        img { src: image_src(&mod_data.icon, ImageSize::Icon), style: "width: 50px; height: 50px; border-radius: 4px; object-fit: cover;" }
+                    }
+                    div { style: "display: flex; flex-direction: column; gap: 2px;",
+                        span { style: "font-weight: bold; color: var(--text-primary);", "{mod_data.name}" }
+                        span { style: "font-size: 11px; color: var(--brand-primary);", "By {mod_data.authors}" }
+                        span { style: "font-size: 12px; color: var(--text-secondary);", "{version.display_name} • {mod_data.content_type.label()}" }
+                    }
+                }
+                p { style: "margin: 0; font-size: 12px; color: var(--text-secondary);", "{mod_data.summary}" }
+                span { style: "font-size: 11px; color: var(--text-secondary);", "Installs into: {app_settings.read().active_instance().name}" }
+            },
+        }
+
+        if let Some(err) = error_msg() {
+            span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+        }
+
+        div { style: "display: flex; gap: 10px; margin-top: 10px;",
+            button {
+                class: "btn btn-ghost",
+                style: "flex: 1; background-color: var(--bg-quaternary);",
+                onclick: move |_| on_close.call(()),
+                "Cancel"
+            }
+            button {
+                class: "btn btn-brand",
+                style: "flex: 1;",
+                disabled: is_working() || !matches!(target(), Some(Ok(_))),
+                onclick: handle_install,
+                if is_working() { "Installing..." } else { "Install" }
+            }
+        }
+    }
+}
+
+#[component]
+fn ImportPackLink(url: String, on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut mod_store = use_context::<Signal<ModStore>>();
+    let mut progress = use_signal(|| Option::<(usize, usize)>::None);
+    let mut failures = use_signal(Vec::<String>::new);
+    let mut error_msg = use_signal(|| Option::<String>::None);
+    let mut include_optional = use_signal(|| false);
+
+    let pack_url = url.clone();
+    let pack = use_resource(move || {
+        let url = pack_url.clone();
+        async move { fetch_pack(&url).await }
+    });
+
+    let handle_import = move |_| {
+        let Some(Ok(pack)) = pack() else { return };
+        if app_settings.read().get_game_folder().is_none() {
+            error_msg.set(Some("No Game Folder Set".to_string()));
+            return;
+        }
+
+        let instance = app_settings.read().active_instance;
+        failures.set(Vec::new());
+        error_msg.set(None);
+
+        spawn(async move {
+            let ModPack { files, .. } = pack;
+            let files: Vec<_> = files.into_iter().filter(|f| f.required || include_optional()).collect();
+            let total = files.len();
+            for (index, file) in files.into_iter().enumerate() {
+                progress.set(Some((index, total)));
+
+                let settings = app_settings.read().for_provider(&ApiProvider::CurseForge);
+                let mod_id = file.project_id.to_string();
+                let file_id = file.file_id.to_string();
+                let result = match resolve_mod(&settings, &ApiProvider::CurseForge, &mod_id, Some(&file_id)).await {
+                    Ok((mod_data, version)) => match download_version(&settings, &mod_data, &version).await {
+                        Ok(downloaded) => downloaded.install(instance, &mut app_settings.write()),
+                        Err(e) => Err(e),
+                    },
+                    Err(e) => Err(e),
+                };
+
+                match result {
+                    Ok(()) => {}
+                    // The pack does not work without it, so there is no point installing the rest.
+                    Err(e) if file.required => {
+                        failures.write().push(format!("Required {} / {}: {}", mod_id, file_id, e));
+                        error_msg.set(Some("Import stopped because a required file failed. Files installed so far stay installed.".to_string()));
+                        break;
+                    }
+                    Err(e) => failures.write().push(format!("Optional {} / {} skipped: {}", mod_id, file_id, e)),
+                }
+            }
+            progress.set(None);
+
+            let mut store = mod_store.write();
+            store.invalidate();
+            store.trigger_refresh();
+            drop(store);
+
+            if failures.read().is_empty() {
+                on_close.call(());
+            }
+        });
+    };
+
+    rsx! {
+        h3 { style: "margin: 0; color: var(--text-primary);", "Import Modpack?" }
+        span { style: "font-size: 11px; color: var(--text-secondary); word-break: break-all;", "{url}" }
+
+        match pack() {
+            None => rsx! {
+                div { style: "display: flex; gap: 10px; align-items: center; color: var(--text-secondary);",
+                    div { class: "spinner" }
+                    span { "Fetching pack..." }
+                }
+            },
+            Some(Err(e)) => rsx! {
+                span { style: "font-size: 12px; color: var(--danger);", "{e}" }
+            },
+            Some(Ok(pack)) => {
+                let optional = pack.files.iter().filter(|f| !f.required).count();
+                rsx! {
+                    div { style: "display: flex; flex-direction: column; gap: 2px;",
+                        span { style: "font-weight: bold; color: var(--text-primary);", "{pack.name} {pack.version}" }
+                        if !pack.author.is_empty() {
+                            span { style: "font-size: 11px; color: var(--brand-primary);", "By {pack.author}" }
+                        }
+                        span { style: "font-size: 12px; color: var(--text-secondary);", "{pack.files.len() - optional} CurseForge file(s) into {app_settings.read().active_instance().name}" }
+                    }
+                    if optional > 0 {
+                        label { style: "display: flex; gap: 6px; align-items: center; font-size: 12px; color: var(--text-primary);",
+                            input {
+                                r#type: "checkbox",
+                                checked: include_optional(),
+                                onchange: move |e| include_optional.set(e.checked()),
+                            }
+                            "Also install {optional} optional file(s)"
+                        }
+                    }
+                }
+            },
+        }
+
+        if let Some((done, total)) = progress() {
+            span { style: "font-size: 12px; color: var(--text-secondary);", "Installing {done + 1} of {total}..." }
+        }
+        for failure in failures.read().iter() {
+            span { style: "font-size: 11px; color: var(--danger);", "{failure}" }
+        }
+        if let Some(err) = error_msg() {
+            span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+        }
+
+        div { style: "display: flex; gap: 10px; margin-top: 10px;",
+            button {
+                class: "btn btn-ghost",
+                style: "flex: 1; background-color: var(--bg-quaternary);",
+                onclick: move |_| on_close.call(()),
+                if failures.read().is_empty() { "Cancel" } else { "Close" }
+            }
+            button {
+                class: "btn btn-brand",
+                style: "flex: 1;",
+                disabled: progress().is_some() || !matches!(pack(), Some(Ok(_))),
+                onclick: handle_import,
+                if progress().is_some() { "Installing..." } else { "Install All" }
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use dioxus::prelude::*;
+use crate::api::image_cache::{image_src, ImageSize};
+use crate::api::local_mods::installed_file_id;
+use crate::api::settings::AppSettings;
+use crate::api::ui_mod::{resolve_dependencies_unified, DependencyRelation, ResolvedDependency, UiMod, UiModVersion};
+use crate::components::drop_down::ThemeDropdown;
+use crate::state::mod_actions::spawn_install_all;
+use crate::state::mod_store::ModStore;
+
+/// What a file needs or clashes with, including what its required dependencies need in turn. Missing
+/// required dependencies, and any optional ones the user ticks, are installed together with the file, each
+/// at a file for the game versions the selected file supports. Nothing is installed while an incompatible
+/// mod is.
+#[component]
+pub fn DependenciesTab(
+    mod_data: UiMod,
+    /// Versions loaded so far on the Versions tab; the file whose dependencies are shown is picked among them.
+    versions: Vec<UiModVersion>,
+    on_open: EventHandler<UiMod>,
+    error_msg: Signal<Option<String>>,
+) -> Element {
+    let app_settings = use_context::<Signal<AppSettings>>();
+    let mod_store = use_context::<Signal<ModStore>>();
+
+    let mut choices = vec![mod_data.version.clone()];
+    choices.extend(versions.into_iter().filter(|v| v.file_id != mod_data.version.file_id));
+
+    let mut selected_file = use_signal(|| mod_data.version.file_id.clone());
+    let mut chosen_optional = use_signal(Vec::<String>::new);
+
+    let selected_index = choices.iter().position(|v| v.file_id == selected_file()).unwrap_or(0);
+    let version = choices[selected_index].clone();
+
+    let dependencies = use_resource(use_reactive((&mod_data.id, &version), move |(mod_id, version)| async move {
+        let settings = app_settings.read().clone();
+        resolve_dependencies_unified(&settings, &mod_id, &version).await
+    }));
+
+    let is_installed = move |mod_id: &str| installed_file_id(&app_settings.read(), mod_id).is_some();
+
+    // Missing required dependencies plus the optional ones ticked, in list order, then the file itself.
+    let resolved = dependencies().and_then(Result::ok).unwrap_or_default();
+    let mut installs: Vec<(UiMod, UiModVersion)> = resolved.iter()
+        .filter_map(|resolved| {
+            let (dependency, dep_mod) = (&resolved.dependency, resolved.mod_data.as_ref()?);
+            let wanted = dependency.relation == DependencyRelation::Required
+                || (dependency.relation == DependencyRelation::Optional && chosen_optional().contains(&dependency.mod_id));
+            (wanted && !is_installed(&dep_mod.id)).then(|| (dep_mod.clone(), dep_mod.version.clone()))
+        })
+        .collect();
+    let conflicts: Vec<String> = resolved.iter()
+        .filter(|r| r.dependency.relation == DependencyRelation::Incompatible && is_installed(&r.dependency.mod_id))
+        .map(|r| r.mod_data.as_ref().map(|m| m.name.clone()).unwrap_or_else(|| format!("mod {}", r.dependency.mod_id)))
+        .collect();
+    let dependency_count = installs.len();
+    let file_installed = installed_file_id(&app_settings.read(), &mod_data.id).as_deref() == Some(version.file_id.as_str());
+    if !file_installed {
+        installs.push((mod_data.clone(), version.clone()));
+    }
+    let conflict_names = conflicts.join(", ");
+    let is_processing = installs.iter().any(|(m, _)| mod_store.read().is_processing(&m.id));
+    let nothing_to_install = installs.is_empty();
+    let file_names: Vec<String> = choices.iter().map(|v| v.display_name.clone()).collect();
+
+    let install_label = match (file_installed, dependency_count) {
+        (true, 0) => "Everything Installed".to_string(),
+        (true, n) => format!("Install {} Missing Dependencies", n),
+        (false, 0) => format!("Install {}", version.display_name),
+        (false, n) => format!("Install {} + {} Dependencies", version.display_name, n),
+    };
+
+    rsx! {
+        div { style: "display: flex; flex-direction: column; gap: 15px;",
+            div { style: "display: flex; gap: 10px; align-items: center;",
+                div { style: "width: 260px;",
+                    ThemeDropdown {
+                        items: file_names,
+                        upwards: false,
+                        placeholder: "File",
+                        default_index: Some(selected_index),
+                        on_select: move |i: usize| {
+                            if let Some(v) = choices.get(i) {
+                                selected_file.set(v.file_id.clone());
+                                chosen_optional.set(Vec::new());
+                            }
+                        }
+                    }
+                }
+                div { style: "flex: 1;" }
+                button {
+                    class: "btn btn-brand",
+                    disabled: nothing_to_install || is_processing || !conflicts.is_empty(),
+                    onclick: move |_| {
+                        if app_settings.read().get_game_folder().is_none() {
+                            error_msg.set(Some("No Game Folder Set".to_string()));
+                            return;
+                        }
+                        spawn_install_all(app_settings, mod_store, error_msg, installs.clone());
+                    },
+                    if is_processing { "WORKING..." } else { "{install_label}" }
+                }
+            }
+
+            if !conflicts.is_empty() {
+                span { style: "color: var(--danger); font-size: 13px;",
+                    "Conflicts with installed {conflict_names}. Remove it before installing this file."
+                }
+            }
+
+            match dependencies() {
+                _ if version.dependencies.is_empty() => rsx! {
+                    span { style: "color: var(--text-secondary); font-size: 13px;", "This file lists no dependencies." }
+                },
+                None => rsx! {
+                    span { style: "color: var(--text-secondary); font-size: 13px;", "Loading dependencies..." }
+                },
+                Some(Err(e)) => rsx! {
+                    span { style: "color: var(--danger); font-size: 13px;", "Could not load dependencies: {e}" }
+                },
+                Some(Ok(resolved)) => rsx! {
+                    div { style: "display: grid; grid-template-columns: repeat(auto-fill, minmax(260px, 1fr)); gap: 10px;",
+                        for ResolvedDependency { dependency, mod_data: dep_mod, required_by } in resolved {
+                            DependencyCard {
+                                key: "{dependency.mod_id}",
+                                relation: dependency.relation,
+                                mod_id: dependency.mod_id.clone(),
+                                installed: is_installed(&dependency.mod_id),
+                                dep_mod: dep_mod.clone(),
+                                required_by: required_by.clone(),
+                                chosen: chosen_optional().contains(&dependency.mod_id),
+                                on_toggle: move |id: String| {
+                                    let mut chosen = chosen_optional.write();
+                                    if let Some(pos) = chosen.iter().position(|c| *c == id) {
+                                        chosen.remove(pos);
+                                    } else {
+                                        chosen.push(id);
+                                    }
+                                },
+                                on_open: move |m| on_open.call(m),
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn DependencyCard(
+    relation: DependencyRelation,
+    mod_id: String,
+    installed: bool,
+    dep_mod: Option<UiMod>,
+    /// The dependency that needs this one, when it is not needed by the file directly.
+    required_by: Option<String>,
+    /// Whether an optional dependency is ticked to be installed along.
+    chosen: bool,
+    on_toggle: EventHandler<String>,
+    on_open: EventHandler<UiMod>,
+) -> Element {
+    let relation_color = match relation {
+        DependencyRelation::Required => "var(--brand-primary)",
+        DependencyRelation::Incompatible => "var(--danger)",
+        DependencyRelation::Optional => "var(--warning)",
+        _ => "var(--text-secondary)",
+    };
+    let (status, status_color) = match relation {
+        DependencyRelation::Embedded | DependencyRelation::Include => ("Bundled", "var(--text-secondary)"),
+        DependencyRelation::Incompatible if installed => ("Installed — conflicts", "var(--danger)"),
+        _ if installed => ("✔ Installed", "var(--success)"),
+        _ => ("Not installed", "var(--text-secondary)"),
+    };
+    let can_choose = relation == DependencyRelation::Optional && !installed && dep_mod.is_some();
+
+    let name = dep_mod.as_ref().map(|m| m.name.clone()).unwrap_or_else(|| format!("Unknown mod {}", mod_id));
+    let icon = dep_mod.as_ref().map(|m| m.icon.clone()).unwrap_or_default();
+    let authors = dep_mod.as_ref().map(|m| m.authors.clone()).unwrap_or_default();
+
+    rsx! {
+        div {
+            style: "cursor: pointer; background-color: var(--bg-tertiary); padding: 10px; border-radius: 8px; display: flex; align-items: center; gap: 10px;",
+            onclick: move |_| {
+                if let Some(m) = dep_mod.clone() {
+                    on_open.call(m);
+                }
+            },
+
+            div {
+                style: "width: 36px; height: 36px; flex-shrink: 0; background-color: var(--bg-secondary); border-radius: 4px; overflow: hidden; display: flex; align-items: center; justify-content: center;",
+                if !icon.is_empty() {
+                    img { src: image_src(&icon, ImageSize::Icon), style: "width: 100%; height: 100%; object-fit: cover;" }
+                } else {
+                    span { style: "font-size: 9px; color: var(--text-secondary);", "IMG" }
+                }
+            }
+            div { style: "flex: 1; min-width: 0; display: flex; flex-direction: column; gap: 2px;",
+                span { style: "font-weight: bold; font-size: 13px; color: var(--text-primary); overflow: hidden; text-overflow: ellipsis; white-space: nowrap;", "{name}" }
+                if !authors.is_empty() {
+                    span { style: "font-size: 11px; color: var(--text-secondary);", "By {authors}" }
+                }
+                if let Some(parent) = required_by {
+                    span { style: "font-size: 11px; color: var(--text-secondary);", "Needed by {parent}" }
+                }
+                div { style: "display: flex; gap: 6px; font-size: 10px;",
+                    span { style: "color: white; background: {relation_color}; padding: 1px 6px; border-radius: 4px; text-transform: uppercase;", "{relation.label()}" }
+                    span { style: "color: {status_color};", "{status}" }
+                }
+            }
+            if can_choose {
+                input {
+                    r#type: "checkbox",
+                    title: "Install with this mod",
+                    checked: chosen,
+                    onclick: |e| e.stop_propagation(),
+                    onchange: move |_| on_toggle.call(mod_id.clone()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use crate::api::game_detect::validate_root;
+use crate::api::settings::AppSettings;
+
+#[component]
+pub fn DetectFolderDialog(candidates: Vec<PathBuf>, on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut error_msg = use_signal(|| Option::<String>::None);
+
+    let pick_manually = move |_| {
+        spawn(async move {
+            if let Some(path) = rfd::AsyncFileDialog::new().pick_folder().await {
+                let path = path.path().to_owned();
+                let layout = app_settings.read().active_instance().layout.clone();
+                match validate_root(&path, &layout) {
+                    Ok(_) => {
+                        app_settings.write().set_game_folder(Some(path));
+                        on_close.call(());
+                    }
+                    Err(e) => error_msg.set(Some(e)),
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 99;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 480px; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--border-color); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+                onclick: |e| e.stop_propagation(),
+
+                h3 { style: "margin: 0; color: var(--text-primary);", "Hytale Found" }
+                p { style: "margin: 0; font-size: 12px; color: var(--text-secondary);", "Select the installation to manage. You can change it later with 📂 Set Game Folder." }
+
+                div { style: "display: flex; flex-direction: column; gap: 8px;",
+                    for candidate in candidates.into_iter() {
+                        {
+                            let display = candidate.display().to_string();
+                            rsx! {
+                                button {
+                                    key: "{display}",
+                                    class: "btn btn-neutral",
+                                    style: "width: 100%; font-family: monospace; font-size: 12px; word-break: break-all; text-align: left;",
+                                    onclick: move |_| {
+                                        app_settings.write().set_game_folder(Some(candidate.clone()));
+                                        on_close.call(());
+                                    },
+                                    "{display}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(err) = error_msg() {
+                    span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+                }
+
+                div { style: "display: flex; gap: 10px; margin-top: 10px;",
+                    button {
+                        class: "btn btn-ghost",
+                        style: "flex: 1; background-color: var(--bg-quaternary);",
+                        onclick: move |_| on_close.call(()),
+                        "Skip"
+                    }
+                    button {
+                        class: "btn btn-brand",
+                        style: "flex: 1;",
+                        onclick: pick_manually,
+                        "Choose Manually..."
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use crate::api::direct_install::{download_from_url, identify_file, local_entry, read_local_file};
+use crate::api::local_mods::{install_mod, validate_mod_archive};
+use crate::api::settings::{AppSettings, ContentType};
+use crate::components::drop_down::ThemeDropdown;
+use crate::state::mod_store::ModStore;
+
+#[component]
+pub fn InstallFileDialog(initial_path: Option<PathBuf>, on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut mod_store = use_context::<Signal<ModStore>>();
+
+    let mut file_path = use_signal(|| initial_path.clone());
+    let mut url = use_signal(String::new);
+    let mut content_type = use_signal(|| ContentType::Plugin);
+    let mut identify = use_signal(|| false);
+    let mut is_working = use_signal(|| false);
+    let mut error_msg = use_signal(|| Option::<String>::None);
+
+    let pick_file = move |_| {
+        spawn(async move {
+            if let Some(file) = rfd::AsyncFileDialog::new()
+                .add_filter("Hytale mods", &["jar", "zip"])
+                .pick_file()
+                .await
+            {
+                file_path.set(Some(file.path().to_owned()));
+                url.set(String::new());
+            }
+        });
+    };
+
+    let handle_drop = move |e: DragEvent| {
+        e.prevent_default();
+        if let Some(file) = e.data_transfer().files().first() {
+            file_path.set(Some(file.path()));
+            url.set(String::new());
+        }
+    };
+
+    let handle_install = move |_| {
+        if app_settings.read().get_game_folder().is_none() {
+            error_msg.set(Some("No Game Folder Set".to_string()));
+            return;
+        }
+
+        let source_path = file_path();
+        let source_url = url().trim().to_string();
+        if source_path.is_none() && source_url.is_empty() {
+            error_msg.set(Some("Choose a file or enter a URL".to_string()));
+            return;
+        }
+
+        let chosen_type = content_type();
+        let should_identify = identify();
+        let provider = app_settings.read().api_provider.clone();
+        let instance = app_settings.read().active_instance;
+
+        is_working.set(true);
+        error_msg.set(None);
+
+        spawn(async move {
+            let loaded = match source_path {
+                Some(path) => read_local_file(&path),
+                None => download_from_url(&source_url).await,
+            };
+
+            let result = match loaded {
+                Ok((file_name, data)) => match validate_mod_archive(&file_name, &data, chosen_type) {
+                    Ok(_) => {
+                        let entry = if should_identify {
+                            identify_file(&file_name, &data, chosen_type, &provider).await
+                        } else {
+                            local_entry(&file_name, &data, chosen_type, &provider)
+                        };
+                        install_mod(&file_name, &data, entry, instance, &mut app_settings.write())
+                    }
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+
+            is_working.set(false);
+            match result {
+                Ok(_) => {
+                    let mut store = mod_store.write();
+                    store.invalidate();
+                    store.trigger_refresh();
+                    on_close.call(());
+                }
+                Err(e) => error_msg.set(Some(e)),
+            }
+        });
+    };
+
+    let file_display = file_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "No file chosen".to_string());
+    let type_labels: Vec<String> = ContentType::ALL.iter().map(|t| t.label().to_string()).collect();
+    let type_index = ContentType::ALL.iter().position(|t| *t == content_type()).unwrap_or(0);
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 99;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 480px; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--border-color); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+                onclick: |e| e.stop_propagation(),
+                ondragover: |e| e.prevent_default(),
+                ondrop: handle_drop,
+
+                h3 { style: "margin: 0; color: var(--text-primary);", "Install from File or URL" }
+                p { style: "margin: 0; font-size: 10px; color: var(--text-secondary);", "Choose a .jar or .zip, drop one here, or paste a direct download link." }
+
+                div { style: "display: flex; gap: 10px; align-items: center;",
+                    button { class: "btn btn-secondary", onclick: pick_file, "📂 Choose File..." }
+                    span { style: "flex: 1; font-size: 11px; color: var(--text-secondary); white-space: nowrap; overflow: hidden; text-overflow: ellipsis;", "{file_display}" }
+                }
+
+                div { style: "display: flex; flex-direction: column; gap: 5px;",
+                    label { style: "font-size: 12px; color: var(--text-secondary);", "Or URL" }
+                    input {
+                        placeholder: "https://...",
+                        value: "{url}",
+                        oninput: move |e| {
+                            url.set(e.value());
+                            file_path.set(None);
+                        }
+                    }
+                }
+
+                div { style: "display: flex; flex-direction: column; gap: 5px;",
+                    label { style: "font-size: 12px; color: var(--text-secondary);", "Content Type" }
+                    ThemeDropdown {
+                        items: type_labels,
+                        upwards: false,
+                        placeholder: "Content Type",
+                        default_index: Some(type_index),
+                        on_select: move |idx: usize| content_type.set(ContentType::ALL[idx]),
+                    }
+                }
+
+                label { style: "display: flex; gap: 8px; align-items: center; font-size: 12px; color: var(--text-secondary);",
+                    input {
+                        r#type: "checkbox",
+                        checked: identify(),
+                        onchange: move |e| identify.set(e.checked()),
+                    }
+                    "Identify online (CurseForge fingerprint, or embedded manifest name and file hash)"
+                }
+
+                if let Some(err) = error_msg() {
+                    span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+                }
+
+                div { style: "display: flex; gap: 10px; margin-top: 10px;",
+                    button {
+                        class: "btn btn-ghost",
+                        style: "flex: 1; background-color: var(--bg-quaternary);",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        class: "btn btn-brand",
+                        style: "flex: 1;",
+                        disabled: is_working(),
+                        onclick: handle_install,
+                        if is_working() { "Installing..." } else { "Install" }
+                    }
+                }
+            }
+        }
+    }
+}
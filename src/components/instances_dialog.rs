@@ -0,0 +1,163 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use crate::api::game_detect::validate_root;
+use crate::api::settings::{AppSettings, GameInstance, ModsLayout};
+use crate::components::drop_down::ThemeDropdown;
+
+#[component]
+pub fn InstancesDialog(on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+
+    let mut new_name = use_signal(String::new);
+    let mut new_root = use_signal(|| Option::<PathBuf>::None);
+    let mut new_layout = use_signal(|| 0usize);
+    let mut custom_dir = use_signal(String::new);
+    let mut error_msg = use_signal(|| Option::<String>::None);
+
+    let pick_root = move |_| {
+        spawn(async move {
+            if let Some(path) = rfd::AsyncFileDialog::new().pick_folder().await {
+                new_root.set(Some(path.path().to_owned()));
+            }
+        });
+    };
+
+    let handle_add = move |_| {
+        let name = new_name().trim().to_string();
+        if name.is_empty() {
+            error_msg.set(Some("Give the instance a name".to_string()));
+            return;
+        }
+        let Some(root) = new_root() else {
+            error_msg.set(Some("Choose the instance folder".to_string()));
+            return;
+        };
+        let layout = match new_layout() {
+            0 => ModsLayout::Client,
+            1 => ModsLayout::Server,
+            _ => {
+                let dir = custom_dir().trim().to_string();
+                if dir.is_empty() {
+                    error_msg.set(Some("Enter the mods folder for the custom layout".to_string()));
+                    return;
+                }
+                ModsLayout::Custom(PathBuf::from(dir))
+            }
+        };
+
+        if let Err(e) = validate_root(&root, &layout) {
+            error_msg.set(Some(e));
+            return;
+        }
+
+        app_settings.write().add_instance(GameInstance::new(name, Some(root), layout));
+        new_name.set(String::new());
+        new_root.set(None);
+        custom_dir.set(String::new());
+        error_msg.set(None);
+    };
+
+    let instances = app_settings.read().instances.clone();
+    let active_index = app_settings.read().active_instance;
+    let can_remove = instances.len() > 1;
+    let new_root_display = new_root().map(|p| p.display().to_string()).unwrap_or_else(|| "No folder chosen".to_string());
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 99;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 560px; max-height: 85%; overflow-y: auto; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--border-color); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+                onclick: |e| e.stop_propagation(),
+
+                h3 { style: "margin: 0; color: var(--text-primary);", "Instances" }
+                p { style: "margin: 0; font-size: 10px; color: var(--text-secondary);", "Each instance has its own mods folder and manifest. Installs, removals and updates apply to the selected one." }
+
+                div { style: "display: flex; flex-direction: column; gap: 8px;",
+                    for (index, instance) in instances.into_iter().enumerate() {
+                        div {
+                            key: "{index}",
+                            style: "display: flex; align-items: center; gap: 10px; background-color: var(--bg-secondary); padding: 10px; border-radius: 6px;",
+                            div { style: "flex: 1; display: flex; flex-direction: column; min-width: 0;",
+                                div { style: "display: flex; gap: 8px; align-items: center;",
+                                    span { style: "color: var(--text-primary); font-weight: bold;", "{instance.name}" }
+                                    span { style: "font-size: 10px; color: var(--text-secondary); padding: 2px 6px; border-radius: 4px; border: 1px solid var(--border-color);", "{instance.layout.label()}" }
+                                    span { style: "font-size: 10px; color: var(--text-secondary);", "{instance.installed_mods.len()} managed" }
+                                }
+                                span {
+                                    style: "font-size: 11px; color: var(--text-secondary); white-space: nowrap; overflow: hidden; text-overflow: ellipsis;",
+                                    if let Some(dir) = instance.mods_dir() { "{dir.display()}" } else { "No folder set" }
+                                }
+                            }
+                            if index == active_index {
+                                span { style: "font-size: 12px; color: var(--brand-primary);", "Selected" }
+                            } else {
+                                button {
+                                    class: "btn btn-secondary",
+                                    style: "padding: 5px 12px; font-size: 12px;",
+                                    onclick: move |_| app_settings.write().select_instance(index),
+                                    "Select"
+                                }
+                            }
+                            if can_remove {
+                                button {
+                                    class: "btn btn-ghost",
+                                    style: "padding: 5px 12px; font-size: 12px;",
+                                    title: "Forget this instance. Files on disk are left untouched.",
+                                    onclick: move |_| app_settings.write().remove_instance(index),
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { style: "display: flex; flex-direction: column; gap: 8px; border-top: 1px solid var(--border-color); padding-top: 15px;",
+                    label { style: "font-size: 12px; color: var(--text-secondary);", "Add Instance" }
+                    input {
+                        placeholder: "Name (e.g. Pre-release, Survival Server)",
+                        value: "{new_name}",
+                        oninput: move |e| new_name.set(e.value())
+                    }
+                    div { style: "display: flex; gap: 10px; align-items: center;",
+                        button { class: "btn btn-secondary", onclick: pick_root, "📂 Choose Folder" }
+                        span { style: "flex: 1; font-size: 11px; color: var(--text-secondary); white-space: nowrap; overflow: hidden; text-overflow: ellipsis;", "{new_root_display}" }
+                    }
+                    ThemeDropdown {
+                        items: ["Client (UserData/Mods)".to_string(), "Server (mods)".to_string(), "Custom".to_string()],
+                        upwards: false,
+                        placeholder: "Layout",
+                        default_index: Some(new_layout()),
+                        on_select: move |idx| new_layout.set(idx),
+                    }
+                    if new_layout() == 2 {
+                        input {
+                            placeholder: "Mods folder, relative to the instance folder",
+                            value: "{custom_dir}",
+                            oninput: move |e| custom_dir.set(e.value())
+                        }
+                    }
+                    if let Some(err) = error_msg() {
+                        span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+                    }
+                }
+
+                div { style: "display: flex; gap: 10px; margin-top: 10px;",
+                    button {
+                        class: "btn btn-ghost",
+                        style: "flex: 1; background-color: var(--bg-quaternary);",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                    button {
+                        class: "btn btn-brand",
+                        style: "flex: 1;",
+                        onclick: handle_add,
+                        "Add Instance"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::api::manual_download::{Candidate, ManualDownload};
+use crate::api::mods_watcher::watch_dir;
+use crate::api::settings::AppSettings;
+
+/// Guides the user through downloading a file whose author disabled third-party downloads: opens the file's
+/// page, waits for the file to land in Downloads, then moves it into the game folder.
+#[component]
+pub fn ManualDownloadDialog(download: ManualDownload, on_close: EventHandler<()>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let download = use_signal(|| download);
+    let mut status = use_signal(|| Option::<String>::None);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut installed = use_signal(|| false);
+    // The file goes where the user was when they asked for it, even if they switch instances while waiting.
+    let instance = use_hook(|| app_settings.peek().active_instance);
+
+    let mut install = move |path: PathBuf| {
+        let result = download.read().install_from(&path, instance, &mut app_settings.write());
+        match result {
+            Ok(()) => {
+                installed.set(true);
+                error.set(None);
+            }
+            Err(e) => error.set(Some(e)),
+        }
+    };
+
+    let mut open_page = move || {
+        if let Err(e) = open::that(download.read().page_url()) {
+            error.set(Some(format!("Could not open browser: {}", e)));
+        }
+    };
+
+    use_resource(move || async move {
+        let Some(dir) = ManualDownload::downloads_dir() else {
+            status.set(Some("No Downloads folder found; choose the file once it is downloaded.".to_string()));
+            return;
+        };
+        let (_watcher, mut changes) = match watch_dir(&dir) {
+            Ok(w) => w,
+            Err(e) => {
+                status.set(Some(format!("Cannot watch {}: {}; choose the file once it is downloaded.", dir.display(), e)));
+                return;
+            }
+        };
+        open_page();
+
+        let mut previous = None;
+        loop {
+            let found = download.read().find_in(&dir, previous.as_ref());
+            match &found {
+                Some(Candidate::Match(path)) => {
+                    install(path.clone());
+                    return;
+                }
+                Some(Candidate::Unconfirmed(path, _)) => status.set(Some(format!(
+                    "Found {}, waiting for the download to finish...", path.display()
+                ))),
+                Some(Candidate::Mismatch(path)) => status.set(Some(format!(
+                    "Found {}, but it is not the selected version. Still waiting...", path.display()
+                ))),
+                None => status.set(Some(format!(
+                    "Waiting for {} in {}...", download.read().file_name(), dir.display()
+                ))),
+            }
+
+            // A file that cannot be checked by hash is looked at again shortly, change or not, to see its size settle.
+            let unconfirmed = matches!(found, Some(Candidate::Unconfirmed(..)));
+            previous = found;
+            if unconfirmed {
+                if let Ok(None) = tokio::time::timeout(Duration::from_secs(1), changes.recv()).await {
+                    return;
+                }
+            } else if changes.recv().await.is_none() {
+                return;
+            }
+            // Browsers write under a temporary name and rename when done; let that settle.
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            while changes.try_recv().is_ok() {}
+        }
+    });
+
+    let handle_choose = move |_| {
+        spawn(async move {
+            if let Some(file) = rfd::AsyncFileDialog::new()
+                .add_filter("Hytale mods", &["jar", "zip"])
+                .pick_file()
+                .await
+            {
+                install(file.path().to_owned());
+            }
+        });
+    };
+
+    let mod_name = download.read().mod_data.name.clone();
+    let version_name = download.read().version.display_name.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 100;",
+            onclick: move |e| e.stop_propagation(),
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 520px; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--bg-quaternary); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+
+                h3 { style: "margin: 0; color: var(--text-primary);", "Manual Download" }
+                p { style: "margin: 0; font-size: 12px; color: var(--text-secondary);",
+                    "The author of {mod_name} only allows downloads from the provider's website. Download {version_name} in your browser; it is installed as soon as it appears in your Downloads folder."
+                }
+
+                if installed() {
+                    span { style: "font-size: 13px; color: var(--success);", "✔ {mod_name} {version_name} installed." }
+                } else if let Some(status) = status() {
+                    span { style: "font-size: 12px; color: var(--text-primary); word-break: break-all;", "{status}" }
+                }
+                if let Some(err) = error() {
+                    span { style: "font-size: 12px; color: var(--danger); word-break: break-all;", "{err}" }
+                }
+
+                div { style: "display: flex; gap: 10px; margin-top: 10px;",
+                    if !installed() {
+                        button {
+                            class: "btn btn-ghost",
+                            style: "flex: 1; background-color: var(--bg-quaternary);",
+                            onclick: move |_| open_page(),
+                            "Open Page Again"
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            style: "flex: 1;",
+                            title: "Pick the file if your browser saved it somewhere else. It is copied into the game folder.",
+                            onclick: handle_choose,
+                            "Choose File..."
+                        }
+                    }
+                    button {
+                        class: if installed() { "btn btn-brand" } else { "btn btn-danger" },
+                        style: "flex: 1;",
+                        onclick: move |_| on_close.call(()),
+                        if installed() { "Done" } else { "Cancel" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,4 +2,16 @@ pub mod api_dialog;
 pub mod mod_info;
 pub mod sidebar;
 pub mod drop_down;
-pub mod mod_card;
\ No newline at end of file
+pub mod mod_card;
+pub mod conflicts_panel;
+pub mod instances_dialog;
+pub mod detect_dialog;
+pub mod install_dialog;
+pub mod deep_link_dialog;
+pub mod recovery_dialog;
+pub mod category_filter;
+pub mod manual_download_dialog;
+pub mod changelog_dialog;
+pub mod dependencies_tab;
+pub mod author_dialog;
+pub mod offline_banner;
\ No newline at end of file
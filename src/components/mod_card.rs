@@ -1,9 +1,14 @@
 use dioxus::prelude::*;
 use dioxus::events::MouseData;
-use crate::api::ui_mod::{download_version_unified, search_exact_mod_unified};
-use crate::api::local_mods::{InstallStatus, ModInstallInfo, check_install_status, install_mod, remove_mod};
-use crate::api::settings::{AppSettings, ApiProvider};
-use crate::api::ui_mod::UiMod;
+use crate::api::local_mods::{InstallStatus, ModInstallInfo, check_install_status, installed_file_id};
+use crate::api::manual_download::ManualDownload;
+use crate::api::image_cache::{image_src, ImageSize};
+use crate::api::settings::AppSettings;
+use crate::api::ui_mod::{UiAuthor, UiMod, UiModVersion};
+use crate::components::changelog_dialog::ChangelogDialog;
+use crate::components::manual_download_dialog::ManualDownloadDialog;
+use crate::state::mod_actions::{remove_installed, spawn_install};
+use crate::state::connectivity::{Connectivity, OFFLINE_REASON};
 use crate::state::mod_store::ModStore;
 
 #[derive(PartialEq, Clone, Debug)]
@@ -23,9 +28,10 @@ pub struct ButtonState {
 }
 
 #[component]
-pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) -> Element {
-    let mut app_settings = use_context::<Signal<AppSettings>>();
-    let mut mod_store = use_context::<Signal<ModStore>>();
+pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>, on_open_author: EventHandler<UiAuthor>) -> Element {
+    let app_settings = use_context::<Signal<AppSettings>>();
+    let mod_store = use_context::<Signal<ModStore>>();
+    let connectivity = use_context::<Connectivity>();
 
     let install_info = use_memo(move || {
         if mod_data().id == "0" {
@@ -42,6 +48,17 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
     });
 
     let mut error_msg = use_signal(|| Option::<String>::None);
+    let mut manual_download = use_signal(|| Option::<ManualDownload>::None);
+    let mut pending_update = use_signal(|| Option::<(String, UiModVersion)>::None);
+
+    let mut start_install = move |version: UiModVersion| {
+        let mod_data = mod_data();
+        if mod_data.needs_manual_download(&version) {
+            manual_download.set(Some(ManualDownload::new(&mod_data, &version)));
+        } else {
+            spawn_install(app_settings, mod_store, error_msg, mod_data, version);
+        }
+    };
 
     let button_info = use_memo(move || {
         let store = mod_store.read();
@@ -63,6 +80,13 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
                 disabled: true,
                 action: ButtonAction::None
             }
+        } else if !connectivity.is_online() && target_action != ButtonAction::Remove {
+            ButtonState {
+                text: "OFFLINE",
+                class: "btn-secondary",
+                disabled: true,
+                action: ButtonAction::None
+            }
         } else if current_error.is_some() {
             ButtonState {
                 text: "RETRY",
@@ -95,117 +119,22 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
     let handle_action = move |e: Event<MouseData>| {
         e.stop_propagation();
 
-        let folder_opt = app_settings.read().get_game_folder();
-        let folder = match folder_opt {
-            Some(p) => p,
-            None => {
-                error_msg.set(Some("No Game Folder Set".to_string()));
-                return;
-            }
-        };
-
-        let current_action = button_info().action;
-        if let ButtonAction::None = current_action { return }
-
-        let mod_id = mod_data().id.clone();
-        let file_id = mod_data().version.file_id.clone();
-        let file_name = mod_data().version.file_name.clone();
-        let mod_name = mod_data().name.clone();
-        let version_name = mod_data().version.display_name.clone();
-        let version_data = mod_data().version.clone();
-        let local_file_to_remove = install_info().local_file_name.clone();
-        let provider = app_settings.read().api_provider.clone();
-
-        let mut error_msg_clone = error_msg.clone();
-        let mut settings_signal = app_settings.clone();
-
-        mod_store.write().set_processing(&mod_id, true);
-        error_msg.set(None);
-
-        spawn(async move {
-            match current_action {
-                ButtonAction::Install | ButtonAction::Update => {
-                    if current_action == ButtonAction::Update {
-                        if let Some(old_file) = local_file_to_remove {
-                            let mut settings = settings_signal.write();
-                            match remove_mod(&folder, &old_file, &mut settings) {
-                                Ok(_) => {},
-                                Err(_) => error_msg_clone.set(Some("FAILED to remove old file".to_string())),
-                            }
-                        }
-                    }
-
-                    let mut final_version_data = version_data.clone();
-                    if final_version_data.download_url.is_none() {
-                        if mod_id == "0" {
-                            error_msg_clone.set(Some("Cannot update local-only mod".to_string()));
-                            mod_store.write().set_processing(&mod_id, false);
-                            return;
-                        }
-
-                        let exact_find = search_exact_mod_unified(
-                            &provider,
-                            mod_name.to_string()
-                        ).await;
-
-                        match exact_find {
-                            Ok(mod_found) => {
-                                final_version_data.download_url = mod_found.version.download_url;
-                            },
-                            Err(e) => {
-                                error_msg_clone.set(Some("Failed to resolve download URL".to_string()));
-                                mod_store.write().set_processing(&mod_id, false);
-                                return;
-                            }
-                        }
-                    }
-
-                    let download_res = {
-                        let settings = settings_signal.read();
-                        download_version_unified(&settings, &final_version_data).await
-                    };
+        if app_settings.read().get_game_folder().is_none() {
+            error_msg.set(Some("No Game Folder Set".to_string()));
+            return;
+        }
 
-                    match download_res {
-                        Ok((_, bytes)) => {
-                            let mut settings = settings_signal.write();
-                            match install_mod(
-                                &folder,
-                                &final_version_data.file_name,
-                                &bytes,
-                                mod_id.clone(),
-                                mod_name.clone(),
-                                file_id,
-                                version_name.clone(),
-                                provider,
-                                &mut settings
-                            ) {
-                                Ok(_) => {},
-                                Err(e) => {
-                                    error_msg_clone.set(Some(format!("Install error: {}", e)));
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error_msg_clone.set(Some(format!("Download failed: {}", e)));
-                        }
-                    }
-                }
-                ButtonAction::Remove => {
-                    if let Some(local_name) = local_file_to_remove {
-                        let mut settings = settings_signal.write();
-                        match remove_mod(&folder, &local_name, &mut settings) {
-                            Ok(_) => {},
-                            Err(e) => {
-                                error_msg_clone.set(Some(e));
-                            }
-                        }
-                    }
-                }
-                _ => {},
+        match button_info().action {
+            ButtonAction::Install => start_install(mod_data().version),
+            ButtonAction::Update => match installed_file_id(&app_settings.read(), &mod_data().id) {
+                Some(installed) => pending_update.set(Some((installed, mod_data().version))),
+                None => start_install(mod_data().version),
+            },
+            ButtonAction::Remove => {
+                remove_installed(app_settings, error_msg, install_info().local_file_name);
             }
-
-            mod_store.write().set_processing(&mod_id, false);
-        });
+            ButtonAction::None => {}
+        }
     };
 
     rsx! {
@@ -216,7 +145,7 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
             div {
                 style: "width: 50px; height: 50px; background-color: var(--bg-secondary); border-radius: 4px; overflow: hidden; display: flex; align-items: center; justify-content: center;",
                 if has_icon {
-                    img { src: "{mod_data().icon}", style: "width: 100%; height: 100%; object-fit: cover;" }
+                    img { src: image_src(&mod_data().icon, ImageSize::Icon), style: "width: 100%; height: 100%; object-fit: cover;" }
                 } else {
                     span { style: "font-size: 10px; color: var(--text-secondary);", "IMG" }
                 }
@@ -255,7 +184,9 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
                         }
                     }
                 }
-                div { style: "font-size: 11px; color: var(--brand-primary); margin-bottom: 2px;", "By {mod_data().authors}" }
+                div { style: "font-size: 11px; color: var(--brand-primary); margin-bottom: 2px;",
+                    AuthorLinks { mod_data: mod_data(), on_open: on_open_author }
+                }
                 if let Some(err) = error_msg() {
                     div { style: "font-size: 10px; color: var(--danger);", "{err}" }
                 } else {
@@ -266,9 +197,56 @@ pub fn ModCard(mod_data: ReadOnlySignal<UiMod>, onclick: EventHandler<UiMod>) ->
             button {
                 class: "btn {button_info().class}",
                 disabled: button_info().disabled,
+                title: if button_info().disabled && !connectivity.is_online() { OFFLINE_REASON },
                 onclick: handle_action,
                 "{button_info().text}"
             }
+
+            if let Some(download) = manual_download() {
+                ManualDownloadDialog { download, on_close: move |_| manual_download.set(None) }
+            }
+
+            if let Some((installed, target)) = pending_update() {
+                ChangelogDialog {
+                    mod_data: mod_data(),
+                    installed_file_id: installed,
+                    target,
+                    on_confirm: move |version: UiModVersion| {
+                        pending_update.set(None);
+                        start_install(version);
+                    },
+                    on_close: move |_| pending_update.set(None),
+                }
+            }
+        }
+    }
+}
+
+/// "By Alice, Bob" with each name opening that author's page.
+#[component]
+pub fn AuthorLinks(mod_data: UiMod, on_open: EventHandler<UiAuthor>) -> Element {
+    if mod_data.author_list.is_empty() {
+        return rsx! { span { "By {mod_data.authors}" } };
+    }
+    let last = mod_data.author_list.len() - 1;
+
+    rsx! {
+        span { "By " }
+        for (i, author) in mod_data.author_list.into_iter().enumerate() {
+            span {
+                key: "{author.id}",
+                class: "author-link",
+                title: "Show all projects by {author.name}",
+                onclick: {
+                    let author = author.clone();
+                    move |e: Event<MouseData>| {
+                        e.stop_propagation();
+                        on_open.call(author.clone());
+                    }
+                },
+                "{author.name}"
+            }
+            if i < last { span { ", " } }
         }
     }
-}
\ No newline at end of file
+}
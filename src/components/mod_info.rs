@@ -1,27 +1,98 @@
-use crate::api::ui_mod::{download_version_unified, get_mod_versions_unified, search_exact_mod_unified};
-use crate::api::local_mods::{check_install_status, install_mod, remove_mod, InstallStatus};
+use crate::api::ui_mod::{get_changelog_unified, get_game_versions_unified, get_mod_description_unified, get_mod_versions_page_unified, VersionFilter, CHANNELS};
+use crate::api::local_mods::{check_install_status, installed_file_id, InstallStatus};
+use crate::api::manual_download::ManualDownload;
+use crate::api::image_cache::{image_src, ImageSize};
 use crate::api::settings::AppSettings;
-use crate::api::ui_mod::{UiMod, UiModVersion};
-use crate::components::mod_card::{ButtonAction, ButtonState};
+use crate::api::ui_mod::{UiAuthor, UiMod, UiModVersion};
+use crate::components::changelog_dialog::ChangelogDialog;
+use crate::components::dependencies_tab::DependenciesTab;
+use crate::components::drop_down::ThemeDropdown;
+use crate::components::manual_download_dialog::ManualDownloadDialog;
+use crate::components::mod_card::{AuthorLinks, ButtonAction, ButtonState};
+use crate::state::mod_actions::{remove_installed, spawn_install};
+use crate::state::connectivity::{Connectivity, OFFLINE_REASON};
 use crate::state::mod_store::ModStore;
 use dioxus::events::MouseData;
 use dioxus::prelude::*;
 
+/// Versions requested per page; CurseForge's files endpoint allows at most 50.
+const VERSIONS_PAGE_SIZE: u32 = 50;
+
 #[component]
-pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
-    let mod_data = use_signal(|| mod_data.clone());
-    let mut app_settings = use_context::<Signal<AppSettings>>();
-    let mut mod_store = use_context::<Signal<ModStore>>();
+pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>, on_open_author: EventHandler<UiAuthor>) -> Element {
+    let mut mod_data = use_signal(|| mod_data.clone());
+    let app_settings = use_context::<Signal<AppSettings>>();
+    let mod_store = use_context::<Signal<ModStore>>();
+    let connectivity = use_context::<Connectivity>();
 
     let mut active_tab = use_signal(|| "overview");
-    let mut displayed_versions = use_signal(|| vec![]);
+    let mut error_msg = use_signal(|| Option::<String>::None);
 
-    use_resource(use_reactive(&mod_data().id, move |id| async move {
-        let settings = app_settings.read();
-        if let Ok(versions) = get_mod_versions_unified(&settings, &id).await {
-            displayed_versions.set(versions);
+    // The Versions tab loads a page at a time, starting when it is first opened and continuing as it is scrolled.
+    let mut version_filter = use_signal(VersionFilter::default);
+    let mut displayed_versions = use_signal(Vec::<UiModVersion>::new);
+    let mut next_version_offset = use_signal(|| Some(0u32));
+    let mut versions_total = use_signal(|| Option::<u32>::None);
+    let mut loading_versions = use_signal(|| false);
+
+    let game_versions = use_resource(move || async move {
+        let provider = app_settings.read().api_provider.clone();
+        get_game_versions_unified(&provider, &mod_data().id).await.unwrap_or_default()
+    });
+
+    let mut load_more_versions = move || {
+        let Some(offset) = next_version_offset() else { return };
+        if loading_versions() {
+            return;
         }
-    }));
+        loading_versions.set(true);
+        let filter = version_filter();
+
+        spawn(async move {
+            let settings = app_settings.read().clone();
+            let result = get_mod_versions_page_unified(&settings, &mod_data().id, &filter, offset, VERSIONS_PAGE_SIZE).await;
+            // The filter may have changed while this page was loading.
+            if *version_filter.peek() != filter {
+                return;
+            }
+            match result {
+                Ok(page) => {
+                    displayed_versions.write().extend(page.versions);
+                    next_version_offset.set(page.next_offset);
+                    versions_total.set(page.total);
+                }
+                Err(e) => error_msg.set(Some(format!("Could not load versions: {}", e))),
+            }
+            loading_versions.set(false);
+        });
+    };
+
+    let mut set_version_filter = move |filter: VersionFilter| {
+        version_filter.set(filter);
+        displayed_versions.set(Vec::new());
+        next_version_offset.set(Some(0));
+        versions_total.set(None);
+        loading_versions.set(false);
+        load_more_versions();
+    };
+
+    use_effect(move || {
+        if connectivity.is_online() && active_tab() == "versions" && next_version_offset.peek().is_some_and(|o| o == 0) {
+            load_more_versions();
+        }
+    });
+
+    // Opening a dependency swaps the dialog over to that mod.
+    let show_mod = move |other: UiMod| {
+        mod_data.set(other);
+        active_tab.set("overview");
+        error_msg.set(None);
+        version_filter.set(VersionFilter::default());
+        displayed_versions.set(Vec::new());
+        next_version_offset.set(Some(0));
+        versions_total.set(None);
+        loading_versions.set(false);
+    };
 
     let is_processing = mod_store().is_processing(&mod_data().id);
 
@@ -30,7 +101,36 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
         check_install_status(&settings, &mod_data().id, &mod_data().version.file_id)
     });
 
-    let mut error_msg = use_signal(|| Option::<String>::None);
+    let description = use_resource(move || async move {
+        let provider = app_settings.read().api_provider.clone();
+        if mod_data().id == "0" {
+            return String::new();
+        }
+        get_mod_description_unified(&provider, &mod_data().id).await.unwrap_or_else(|e| {
+            eprintln!("Description fetch failed for {}: {}", mod_data().id, e);
+            String::new()
+        })
+    });
+
+    let mut manual_download = use_signal(|| Option::<ManualDownload>::None);
+    // Replacing an installed file waits for the user to read what changed.
+    let mut pending_update = use_signal(|| Option::<(String, UiModVersion)>::None);
+
+    let mut start_install = move |version: UiModVersion| {
+        if mod_data().needs_manual_download(&version) {
+            manual_download.set(Some(ManualDownload::new(&mod_data(), &version)));
+        } else {
+            spawn_install(app_settings, mod_store, error_msg, mod_data(), version);
+        }
+    };
+
+    let mut install_version = move |version: UiModVersion| {
+        let installed = installed_file_id(&app_settings.read(), &mod_data().id);
+        match installed {
+            Some(installed) if installed != version.file_id => pending_update.set(Some((installed, version))),
+            _ => start_install(version),
+        }
+    };
 
     let button_info = use_memo(move || {
         let store = mod_store.read();
@@ -52,6 +152,13 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                 disabled: true,
                 action: ButtonAction::None
             }
+        } else if !connectivity.is_online() && target_action != ButtonAction::Remove {
+            ButtonState {
+                text: "OFFLINE",
+                class: "btn-secondary",
+                disabled: true,
+                action: ButtonAction::None
+            }
         } else if current_error.is_some() {
             ButtonState {
                 text: "RETRY",
@@ -80,122 +187,22 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
     });
 
     let mod_id_for_versions = mod_data().id.clone();
-    let mod_name_for_versions = mod_data().name.clone();
 
     let handle_action = move |e: Event<MouseData>| {
         e.stop_propagation();
 
-        let folder_opt = app_settings.read().get_game_folder();
-        let folder = match folder_opt {
-            Some(p) => p,
-            None => {
-                error_msg.set(Some("No Game Folder Set".to_string()));
-                return;
-            }
-        };
-
-        let current_action = button_info().action;
-        let mod_id = mod_data().id.clone();
-        let mod_name = mod_data().name.clone();
-
-        let version_data = mod_data().version.clone();
-        let file_id = version_data.file_id.clone();
-        let file_name = version_data.file_name.clone();
-        let version_name = version_data.display_name.clone();
-        let download_url_str = version_data.download_url.clone();
-
-        let local_file_to_remove = install_info().local_file_name.clone();
-        let provider = app_settings.read().api_provider.clone();
-
-        let mut error_msg_clone = error_msg.clone();
-        let mut settings_signal = app_settings.clone();
-
-        mod_store.write().set_processing(&mod_id, true);
-        error_msg.set(None);
-
-        spawn(async move {
-            match current_action {
-                ButtonAction::Install | ButtonAction::Update => {
-                    if current_action == ButtonAction::Update {
-                        if let Some(old_file) = local_file_to_remove {
-                            let mut settings = settings_signal.write();
-                            match remove_mod(&folder, &old_file, &mut settings) {
-                                Ok(_) => {},
-                                Err(_) => error_msg_clone.set(Some("FAILED to remove old file".to_string())),
-                            }
-                        }
-                    }
-
-                    let mut final_version_data = version_data.clone();
-                    if final_version_data.download_url.is_none() {
-                        if mod_id == "0" {
-                            error_msg_clone.set(Some("Cannot update local-only mod".to_string()));
-                            mod_store.write().set_processing(&mod_id, false);
-                            return;
-                        }
-
-                        let exact_find = search_exact_mod_unified(
-                            &provider,
-                            mod_name.to_string()
-                        ).await;
-
-                        match exact_find {
-                            Ok(mod_found) => {
-                                final_version_data.download_url = mod_found.version.download_url;
-                            },
-                            Err(e) => {
-                                error_msg_clone.set(Some("Failed to resolve download URL".to_string()));
-                                mod_store.write().set_processing(&mod_id, false);
-                                return;
-                            }
-                        }
-                    }
+        if app_settings.read().get_game_folder().is_none() {
+            error_msg.set(Some("No Game Folder Set".to_string()));
+            return;
+        }
 
-                    let download_res = {
-                        let settings = settings_signal.read();
-                        download_version_unified(&settings, &final_version_data).await
-                    };
-
-                    match download_res {
-                        Ok((_, bytes)) => {
-                            let mut settings = settings_signal.write();
-                            match install_mod(
-                                &folder,
-                                &final_version_data.file_name,
-                                &bytes,
-                                mod_id.clone(),
-                                mod_name.clone(),
-                                file_id,
-                                version_name.clone(),
-                                provider,
-                                &mut settings
-                            ) {
-                                Ok(_) => {},
-                                Err(e) => {
-                                    error_msg_clone.set(Some(format!("Install error: {}", e)));
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error_msg_clone.set(Some(format!("Download failed: {}", e)));
-                        }
-                    }
-                }
-                ButtonAction::Remove => {
-                    if let Some(local_name) = local_file_to_remove {
-                        let mut settings = settings_signal.write();
-                        match remove_mod(&folder, &local_name, &mut settings) {
-                            Ok(_) => {}
-                            Err(e) => error_msg_clone.set(Some(e)),
-                        }
-                    } else {
-                        error_msg_clone.set(Some("File not found locally".to_string()));
-                    }
-                }
-                _ => {}
+        match button_info().action {
+            ButtonAction::Install | ButtonAction::Update => install_version(mod_data().version),
+            ButtonAction::Remove => {
+                remove_installed(app_settings, error_msg, install_info().local_file_name);
             }
-            mod_store.write().set_processing(&mod_id, false);
-        });
+            ButtonAction::None => {}
+        }
     };
 
     let mut selected_image = use_signal(|| None::<String>);
@@ -219,7 +226,7 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
 
                 div { style: "display: flex; gap: 20px; align-items: center; background-color: var(--bg-tertiary); padding: 20px 30px;",
                     if !mod_data().icon.is_empty() {
-                        img { src: "{mod_data().icon}", style: "width: 64px; height: 64px; border-radius: 8px; object-fit: cover; border: 1px solid var(--bg-quaternary);" }
+                        img { src: image_src(&mod_data().icon, ImageSize::Icon), style: "width: 64px; height: 64px; border-radius: 8px; object-fit: cover; border: 1px solid var(--bg-quaternary);" }
                     }
                     div { style: "flex: 1;",
                         div { style: "display: flex; flex-direction: row; gap: 12px; align-items: center;",
@@ -229,7 +236,9 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                                 "{app_settings.read().api_provider:?}"
                             }
                         }
-                        div { style: "font-size: 14px; color: var(--text-secondary); margin-top: 4px;", "By {mod_data().authors}" }
+                        div { style: "font-size: 14px; color: var(--text-secondary); margin-top: 4px;",
+                            AuthorLinks { mod_data: mod_data(), on_open: on_open_author }
+                        }
                     }
                     button { class: "btn btn-ghost", style: "font-size: 20px;", onclick: move |_| on_close.call(()), "✕" }
                 }
@@ -243,7 +252,14 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
 
                 div { style: "display: flex; flex: 1; overflow: hidden;",
 
-                    div { style: "flex: 1; display: flex; flex-direction: column; overflow-y: auto;",
+                    div {
+                        style: "flex: 1; display: flex; flex-direction: column; overflow-y: auto;",
+                        onscroll: move |e| {
+                            let remaining = e.scroll_height() as f64 - e.scroll_top() - e.client_height() as f64;
+                            if active_tab() == "versions" && remaining < 300.0 {
+                                load_more_versions();
+                            }
+                        },
 
                         div { style: "padding: 10px 30px; display: flex; gap: 20px; border-bottom: 1px solid var(--bg-tertiary); background: var(--bg-secondary); position: sticky; top: 0; z-index: 5;",
                             button {
@@ -254,7 +270,16 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                             button {
                                 class: if active_tab() == "versions" { "btn btn-tab-active" } else { "btn btn-tab" },
                                 onclick: move |_| active_tab.set("versions"),
-                                "Versions ({displayed_versions.read().len()})"
+                                match versions_total() {
+                                    Some(total) => format!("Versions ({})", total),
+                                    None if displayed_versions.read().is_empty() => "Versions".to_string(),
+                                    None => format!("Versions ({}+)", displayed_versions.read().len()),
+                                }
+                            }
+                            button {
+                                class: if active_tab() == "dependencies" { "btn btn-tab-active" } else { "btn btn-tab" },
+                                onclick: move |_| active_tab.set("dependencies"),
+                                "Dependencies ({mod_data().version.dependencies.len()})"
                             }
                         }
 
@@ -267,7 +292,7 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                                 div { style: "display: flex; flex-direction: column; gap: 25px;",
                                     if !mod_data().banner.is_empty() {
                                         img {
-                                            src: "{mod_data().banner}",
+                                            src: image_src(&mod_data().banner, ImageSize::Full),
                                             style: "width: 100%; height: 240px; object-fit: cover; border-radius: 8px; background: var(--bg-tertiary);"
                                         }
                                     }
@@ -287,16 +312,23 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                                         }
                                     }
 
-                                    div {
-                                        style: "line-height: 1.8; color: var(--text-primary); font-size: 15px; white-space: pre-wrap;",
-                                        "{mod_data().summary}"
+                                    match description() {
+                                        Some(html) if !html.trim().is_empty() => rsx! {
+                                            div { class: "rich-text", dangerous_inner_html: "{html}" }
+                                        },
+                                        _ => rsx! {
+                                            div {
+                                                style: "line-height: 1.8; color: var(--text-primary); font-size: 15px; white-space: pre-wrap;",
+                                                "{mod_data().summary}"
+                                            }
+                                        },
                                     }
 
                                     if !mod_data().gallery_urls.is_empty() {
                                         div { style: "display: flex; flex-direction: column; gap: 15px;",
                                             div { style: "width: 100%; aspect-ratio: 16/9; background: #000; border-radius: 8px; overflow: hidden; border: 1px solid var(--bg-quaternary); display: flex; align-items: center; justify-content: center;",
                                                 if let Some(img_src) = current_gallery_image {
-                                                    img { src: "{img_src}", style: "width: 100%; height: 100%; object-fit: contain;" }
+                                                    img { src: image_src(&img_src, ImageSize::Full), style: "width: 100%; height: 100%; object-fit: contain;" }
                                                 }
                                             }
                                             div { style: "display: flex; gap: 10px; overflow-x: auto; padding-bottom: 5px; justify-content: center;",
@@ -309,7 +341,7 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
 
                                                         rsx! {
                                                             img {
-                                                                src: "{image_owned}",
+                                                                src: image_src(&image_owned, ImageSize::Thumbnail),
                                                                 onclick: move |_| selected_image.set(Some(image_owned.clone())),
                                                                 style: "cursor: pointer; width: 100px; height: 60px; object-fit: cover; border-radius: 4px; border: 2px solid {border_color}; opacity: {opacity_val}; transition: all 0.2s;"
                                                             }
@@ -320,77 +352,103 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                                         }
                                     }
                                 }
+                            } else if !connectivity.is_online() {
+                                div { style: "text-align: center; color: var(--text-secondary); margin-top: 30px;", "{OFFLINE_REASON}" }
+                            } else if active_tab() == "dependencies" {
+                                DependenciesTab {
+                                    key: "{mod_data().id}",
+                                    mod_data: mod_data(),
+                                    versions: displayed_versions(),
+                                    on_open: show_mod,
+                                    error_msg,
+                                }
                             } else if active_tab() == "versions" {
+                                div { style: "display: flex; gap: 10px; margin-bottom: 15px;",
+                                    div { style: "width: 200px;",
+                                        {
+                                            let versions = game_versions().unwrap_or_default();
+                                            let selected = version_filter().game_version
+                                                .and_then(|g| versions.iter().position(|v| *v == g))
+                                                .map(|i| i + 1)
+                                                .unwrap_or(0);
+                                            let items: Vec<String> = std::iter::once("All Game Versions".to_string())
+                                                .chain(versions.iter().cloned())
+                                                .collect();
+                                            rsx! {
+                                                ThemeDropdown {
+                                                    items,
+                                                    upwards: false,
+                                                    placeholder: "Game Version",
+                                                    default_index: Some(selected),
+                                                    on_select: move |i: usize| {
+                                                        let game_version = i.checked_sub(1).and_then(|i| versions.get(i).cloned());
+                                                        set_version_filter(VersionFilter { game_version, ..version_filter() });
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    div { style: "width: 160px;",
+                                        ThemeDropdown {
+                                            items: std::iter::once("All Channels")
+                                                .chain(CHANNELS.iter().map(|(_, label)| *label))
+                                                .map(String::from)
+                                                .collect::<Vec<_>>(),
+                                            upwards: false,
+                                            placeholder: "Channel",
+                                            default_index: Some(version_filter().release_type
+                                                .and_then(|t| CHANNELS.iter().position(|(c, _)| *c == t))
+                                                .map(|i| i + 1)
+                                                .unwrap_or(0)),
+                                            on_select: move |i: usize| {
+                                                let release_type = i.checked_sub(1).and_then(|i| CHANNELS.get(i)).map(|(t, _)| *t);
+                                                set_version_filter(VersionFilter { release_type, ..version_filter() });
+                                            }
+                                        }
+                                    }
+                                }
                                 div { style: "display: flex; flex-direction: column; gap: 8px;",
                                     for version in displayed_versions.read().iter() {{
-                                        let mod_name_owned = mod_name_for_versions.clone();
                                         let mod_id_owned = mod_id_for_versions.clone();
                                         let version_data = version.clone();
 
                                         rsx!{
                                             VersionRow {
+                                                key: "{version.file_id}",
+                                                mod_id: mod_id_owned.clone(),
                                                 version: version.clone(),
                                                 is_installed: {
                                                     let current_settings = app_settings.read();
-                                                    if let Some(entry) = current_settings.installed_mods.values().find(|e| e.mod_id == mod_id_owned) {
+                                                    if let Some(entry) = current_settings.installed_mods().values().find(|e| e.mod_id == mod_id_owned) {
                                                         entry.file_id == version.file_id
                                                     } else {
                                                         false
                                                     }
                                                 },
                                                 is_processing: is_processing,
+                                                manual: mod_data().needs_manual_download(version),
                                                 on_install: move |_| {
-                                                    let mod_id = mod_id_owned.clone();
-                                                    let mod_name = mod_name_owned.clone();
-                                                    let folder = match app_settings.read().get_game_folder() {
-                                                        Some(p) => p,
-                                                        None => { error_msg.set(Some("No Game Folder Set".to_string())); return; }
-                                                    };
-
-                                                    let file_id = version_data.file_id.clone();
-                                                    let file_name = version_data.file_name.clone();
-                                                    let version_name = version_data.display_name.clone();
-                                                    let provider = app_settings.read().api_provider.clone();
-
-                                                    let mut error_msg_clone = error_msg.clone();
-                                                    let mut settings_signal = app_settings.clone();
-                                                    let version_clone_for_dl = version_data.clone();
-
-                                                    (mod_store.write()).set_processing(&mod_id, true);
-                                                    error_msg.set(None);
-
-                                                    spawn(async move {
-                                                        let download_res = {
-                                                            let settings = settings_signal.read();
-                                                            download_version_unified(&settings, &version_clone_for_dl).await
-                                                        };
-
-                                                        match download_res {
-                                                            Ok((_, bytes)) => {
-                                                                let mut settings = settings_signal.write();
-                                                                match install_mod(
-                                                                    &folder,
-                                                                    &file_name,
-                                                                    &bytes,
-                                                                    mod_id.clone(),
-                                                                    mod_name,
-                                                                    file_id,
-                                                                    version_name,
-                                                                    provider,
-                                                                    &mut settings
-                                                                ) {
-                                                                    Ok(_) => {},
-                                                                    Err(e) => error_msg_clone.set(Some(e)),
-                                                                }
-                                                            }
-                                                            Err(e) => error_msg_clone.set(Some(e)),
-                                                        }
-                                                        (mod_store.write()).set_processing(&mod_id, false);
-                                                    });
+                                                    if app_settings.read().get_game_folder().is_none() {
+                                                        error_msg.set(Some("No Game Folder Set".to_string()));
+                                                        return;
+                                                    }
+                                                    install_version(version_data.clone());
                                                 }
                                             }
                                         }
                                     }}
+                                    if loading_versions() {
+                                        div { style: "text-align: center; padding: 10px; color: var(--text-secondary); font-size: 12px;", "Loading versions..." }
+                                    } else if next_version_offset().is_some() {
+                                        button {
+                                            class: "btn btn-ghost",
+                                            style: "align-self: center;",
+                                            onclick: move |_| load_more_versions(),
+                                            "Load More"
+                                        }
+                                    } else if displayed_versions.read().is_empty() {
+                                        div { style: "text-align: center; padding: 10px; color: var(--text-secondary); font-size: 12px;", "No versions match these filters." }
+                                    }
                                 }
                             }
                         }
@@ -415,6 +473,7 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                                 class: "btn {button_info().class}",
                                 style: "width: 100%; padding: 12px; font-weight: bold;",
                                 disabled: button_info().disabled,
+                                title: if button_info().disabled && !connectivity.is_online() { OFFLINE_REASON },
                                 onclick: handle_action,
                                 "{button_info().text}"
                             }
@@ -436,15 +495,35 @@ pub fn ModInfoDialog(mod_data: UiMod, on_close: EventHandler<()>) -> Element {
                     }
                 }
             }
+
+            if let Some(download) = manual_download() {
+                ManualDownloadDialog { download, on_close: move |_| manual_download.set(None) }
+            }
+
+            if let Some((installed, target)) = pending_update() {
+                ChangelogDialog {
+                    mod_data: mod_data(),
+                    installed_file_id: installed,
+                    target,
+                    on_confirm: move |version: UiModVersion| {
+                        pending_update.set(None);
+                        start_install(version);
+                    },
+                    on_close: move |_| pending_update.set(None),
+                }
+            }
         }
     }
 }
 
 #[component]
 fn VersionRow(
+    mod_id: String,
     version: UiModVersion,
     is_installed: bool,
     is_processing: bool,
+    /// The author disabled third-party downloads, so installing goes through the manual download flow.
+    manual: bool,
     on_install: EventHandler<UiModVersion>,
 ) -> Element {
     let (type_text, type_color) = match version.release_type {
@@ -454,43 +533,78 @@ fn VersionRow(
         _ => ("?", "var(--text-secondary)"),
     };
 
-    let has_url = version.download_url.is_some();
-
     let (btn_text, btn_class, btn_disabled) = if is_installed {
         ("Installed", "btn btn-ghost", true)
-    } else if !has_url {
-        ("Not Available", "btn btn-secondary", true)
+    } else if manual {
+        ("Manual Download", "btn btn-secondary", false)
     } else {
         ("Install", "btn btn-brand", false)
     };
 
     let display_date = version.upload_date.split('T').next().unwrap_or(&version.upload_date);
 
+    let app_settings = use_context::<Signal<AppSettings>>();
+    let mut expanded = use_signal(|| false);
+    // Fetched the first time the row is expanded, then kept.
+    let mut changelog_wanted = use_signal(|| false);
+    let changelog = use_resource(use_reactive((&mod_id, &version.file_id), move |(mod_id, file_id)| {
+        let wanted = changelog_wanted();
+        let provider = app_settings.read().api_provider.clone();
+        async move {
+            if !wanted {
+                return None;
+            }
+            Some(get_changelog_unified(&provider, &mod_id, &file_id).await)
+        }
+    }));
+    let arrow = if expanded() { "▾" } else { "▸" };
+
     rsx! {
         div {
-            style: "display: flex; align-items: center; background-color: var(--bg-tertiary); padding: 10px; border-radius: 6px; gap: 15px;",
+            style: "display: flex; flex-direction: column; background-color: var(--bg-tertiary); border-radius: 6px;",
             div {
-                style: "background-color: {type_color}; color: white; width: 24px; height: 24px; display: flex; align-items: center; justify-content: center; border-radius: 4px; font-weight: bold; font-size: 12px;",
-                "{type_text}"
-            }
-            div { style: "flex: 1; display: flex; flex-direction: column;",
-                span { style: "color: var(--text-primary); font-weight: bold;", "{version.display_name}" }
-                div { style: "font-size: 12px; color: var(--text-secondary); display: flex; gap: 10px;",
-                    span { "{display_date}" }
-                    span { "•" }
-                    span { "{version.game_versions.join(\", \")}" }
+                style: "display: flex; align-items: center; padding: 10px; gap: 15px;",
+                button {
+                    class: "btn btn-ghost",
+                    style: "padding: 2px 6px; font-size: 12px;",
+                    title: "Changelog",
+                    onclick: move |_| {
+                        changelog_wanted.set(true);
+                        expanded.set(!expanded());
+                    },
+                    "{arrow}"
+                }
+                div {
+                    style: "background-color: {type_color}; color: white; width: 24px; height: 24px; display: flex; align-items: center; justify-content: center; border-radius: 4px; font-weight: bold; font-size: 12px;",
+                    "{type_text}"
+                }
+                div { style: "flex: 1; display: flex; flex-direction: column;",
+                    span { style: "color: var(--text-primary); font-weight: bold;", "{version.display_name}" }
+                    div { style: "font-size: 12px; color: var(--text-secondary); display: flex; gap: 10px;",
+                        span { "{display_date}" }
+                        span { "•" }
+                        span { "{version.game_versions.join(\", \")}" }
+                    }
+                }
+                button {
+                    class: "{btn_class}",
+                    style: "padding: 5px 15px; font-size: 12px;",
+                    disabled: btn_disabled || is_processing,
+                    onclick: move |_| on_install.call(version.clone()),
+                    "{btn_text}"
                 }
             }
-            button {
-                class: "{btn_class}",
-                style: "padding: 5px 15px; font-size: 12px;",
-                disabled: btn_disabled || is_processing,
-                onclick: move |_| {
-                    if has_url {
-                        on_install.call(version.clone())
+            if expanded() {
+                div { style: "padding: 0 15px 12px 49px; font-size: 13px;",
+                    match changelog() {
+                        Some(Some(Ok(html))) if !html.trim().is_empty() => rsx! {
+                            div { class: "rich-text", dangerous_inner_html: "{html}" }
+                        },
+                        Some(Some(Ok(_))) => rsx! { span { style: "color: var(--text-secondary);", "No changelog provided." } },
+                        Some(Some(Err(e))) => rsx! { span { style: "color: var(--danger);", "Could not load changelog: {e}" } },
+                        _ => rsx! { span { style: "color: var(--text-secondary);", "Loading changelog..." } },
                     }
-                },
-                "{btn_text}"
+                }
             }
         }
     }
@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+use crate::api::settings::AppSettings;
+
+/// Shown above every page while the provider cannot be reached.
+#[component]
+pub fn OfflineBanner(on_retry: EventHandler<()>) -> Element {
+    let app_settings = use_context::<Signal<AppSettings>>();
+    let provider = app_settings.read().api_provider.label();
+
+    rsx! {
+        div { style: "display: flex; align-items: center; gap: 15px; background: rgba(255,170,0,0.1); border: 1px solid var(--warning); color: var(--text-primary); padding: 10px 15px; border-radius: 8px; margin-bottom: 10px;",
+            span { style: "font-size: 18px; color: var(--warning);", "⚠" }
+            div { style: "flex: 1; display: flex; flex-direction: column; gap: 2px;",
+                span { style: "font-weight: bold;", "Offline" }
+                span { style: "font-size: 12px; color: var(--text-secondary);",
+                    "{provider} cannot be reached. Installed mods, removing them and installing local files still work; searching, installing and updating come back with the connection."
+                }
+            }
+            button { class: "btn btn-secondary", onclick: move |_| on_retry.call(()), "Retry" }
+        }
+    }
+}
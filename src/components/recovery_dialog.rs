@@ -0,0 +1,77 @@
+use dioxus::prelude::*;
+use crate::api::settings::{config_dir, AppSettings};
+
+/// Shown instead of the app's normal modals when settings.json could not be loaded.
+/// Nothing is written to disk until the user picks an option.
+#[component]
+pub fn RecoveryDialog() -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mut retry_error = use_signal(|| Option::<String>::None);
+
+    let Some(failure) = app_settings.read().recovery().cloned() else {
+        return rsx! {};
+    };
+    let backup_display = failure.backup.as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "No backup could be made".to_string());
+
+    let handle_retry = move |_| {
+        let reloaded = app_settings.peek().retry_load();
+        match reloaded.recovery() {
+            Some(failure) => retry_error.set(Some(failure.error.clone())),
+            None => app_settings.set(reloaded),
+        }
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.7); display: flex; align-items: center; justify-content: center; z-index: 100;",
+
+            div {
+                style: "background-color: var(--bg-tertiary); width: 520px; padding: 25px; border-radius: 10px; display: flex; flex-direction: column; gap: 15px; border: 1px solid var(--danger); box-shadow: 0 4px 15px rgba(0,0,0,0.5);",
+
+                h3 { style: "margin: 0; color: var(--danger);", "Settings Could Not Be Loaded" }
+                p { style: "margin: 0; font-size: 12px; color: var(--text-secondary);",
+                    "Your settings file, including the list of installed mods, is unreadable. It has not been changed, and nothing will be saved until you choose what to do."
+                }
+                span { style: "font-size: 12px; color: var(--danger); font-family: monospace; word-break: break-all;", "{failure.error}" }
+
+                div { style: "display: flex; flex-direction: column; gap: 4px;",
+                    label { style: "font-size: 12px; color: var(--text-secondary);", "Backup" }
+                    span { style: "font-size: 11px; color: var(--text-primary); font-family: monospace; word-break: break-all;", "{backup_display}" }
+                }
+
+                if let Some(err) = retry_error() {
+                    span { style: "font-size: 12px; color: var(--danger);", "Still unreadable: {err}" }
+                }
+
+                div { style: "display: flex; gap: 10px; margin-top: 10px;",
+                    button {
+                        class: "btn btn-ghost",
+                        style: "flex: 1; background-color: var(--bg-quaternary);",
+                        title: "Fix settings.json by hand, then retry",
+                        onclick: move |_| {
+                            if let Err(e) = open::that(config_dir()) {
+                                retry_error.set(Some(format!("Failed to open folder: {}", e)));
+                            }
+                        },
+                        "Open Folder"
+                    }
+                    button {
+                        class: "btn btn-secondary",
+                        style: "flex: 1;",
+                        onclick: handle_retry,
+                        "Retry"
+                    }
+                    button {
+                        class: "btn btn-danger",
+                        style: "flex: 1;",
+                        title: "Continue with default settings. The backup is kept.",
+                        onclick: move |_| app_settings.write().start_fresh(),
+                        "Start Fresh"
+                    }
+                }
+            }
+        }
+    }
+}
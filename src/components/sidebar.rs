@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 use std::path::{Path, PathBuf};
+use crate::api::game_detect::validate_root;
 use crate::api::settings::{AppSettings, AppTheme};
 use crate::components::drop_down::{ThemeDropdown};
 use crate::SidebarTab;
@@ -20,14 +21,25 @@ fn truncate_path(path: &Path, max_chars: usize) -> String {
 pub fn Sidebar(
     active_tab: Signal<SidebarTab>,
     on_open_api: EventHandler<()>,
+    on_open_instances: EventHandler<()>,
 ) -> Element {
     let mut settings_store = use_context::<Signal<AppSettings>>();
 
+    let mut folder_error = use_signal(|| Option::<String>::None);
+
     let game_folder = settings_store().get_game_folder();
     let pick_folder = move |_| {
         spawn(async move {
             if let Some(path) = rfd::AsyncFileDialog::new().pick_folder().await {
-                settings_store.write().set_game_folder(Some(path.path().to_owned()));
+                let path = path.path().to_owned();
+                let layout = settings_store.read().active_instance().layout.clone();
+                match validate_root(&path, &layout) {
+                    Ok(_) => {
+                        folder_error.set(None);
+                        settings_store.write().set_game_folder(Some(path));
+                    }
+                    Err(e) => folder_error.set(Some(e)),
+                }
             }
         });
     };
@@ -38,6 +50,9 @@ pub fn Sidebar(
         String::new()
     };
 
+    let instance_names: Vec<String> = settings_store().instances.iter().map(|i| i.name.clone()).collect();
+    let active_instance = settings_store().active_instance;
+
     let current_theme_label = match settings_store().get_theme() {
         AppTheme::Dark => "Dark Mode",
         AppTheme::Light => "Light Mode",
@@ -51,6 +66,26 @@ pub fn Sidebar(
             span { style: "font-size: 12px; color: var(--text-secondary);", "Curse Forge Client" }
             div { style: "height: 20px;" }
 
+            span { style: "font-size: 12px; color: var(--text-secondary); display: block;", "Instance" }
+            div { style: "display: flex; gap: 6px;",
+                div { style: "flex: 1; min-width: 0;",
+                    ThemeDropdown {
+                        items: instance_names,
+                        upwards: false,
+                        placeholder: "Instance",
+                        default_index: Some(active_instance),
+                        on_select: move |idx| settings_store.write().select_instance(idx),
+                    }
+                }
+                button {
+                    class: "btn btn-neutral",
+                    title: "Manage instances",
+                    onclick: move |_| on_open_instances.call(()),
+                    "⚙"
+                }
+            }
+            div { style: "height: 10px;" }
+
             SidebarBtn {
                 label: "📦 Search Mods",
                 active: active_tab() == SidebarTab::SearchMods,
@@ -63,6 +98,18 @@ pub fn Sidebar(
                 onclick: move |_| active_tab.set(SidebarTab::Installed)
             }
 
+            SidebarBtn {
+                label: "Following",
+                active: active_tab() == SidebarTab::Following,
+                onclick: move |_| active_tab.set(SidebarTab::Following)
+            }
+
+            SidebarBtn {
+                label: "Download Cache",
+                active: active_tab() == SidebarTab::Cache,
+                onclick: move |_| active_tab.set(SidebarTab::Cache)
+            }
+
             div { style: "flex: 1;" }
 
             span { style: "font-size: 12px; color: var(--text-secondary); display: block; margin-bottom: 5px;", "Theme" }
@@ -80,6 +127,10 @@ pub fn Sidebar(
             button { class: "btn btn-brand", onclick: pick_folder, "📂 Set Game Folder" }
             button { class: "btn btn-neutral", onclick: move |_| on_open_api.call(()), "🔑 Set Api Key" }
 
+            if let Some(err) = folder_error() {
+                div { style: "font-size: 10px; color: var(--danger); text-align: center;", "{err}" }
+            }
+
             if let Some(path) = game_folder {
                 div {
                     title: "{path.to_string_lossy()}",
@@ -1,42 +1,104 @@
 #![allow(non_snake_case)]
 
-pub mod api;
+pub use hytale_mod_manager_core as api;
 pub mod components;
 pub mod pages;
 pub mod style;
 mod state;
+mod cli;
+mod url_scheme;
+mod single_instance;
 
+use dioxus::desktop::wry::http::header::CONTENT_TYPE;
+use dioxus::desktop::wry::http::Response;
 use dioxus::desktop::{Config, WindowBuilder};
 use dioxus::prelude::*;
-use crate::api::settings::{AppSettings, AppTheme};
-use crate::api::ui_mod::UiMod;
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use crate::api::deep_link::is_deep_link;
+use crate::api::connectivity::is_reachable;
+use crate::api::game_detect::detect_installations;
+use crate::api::image_cache::{self, IMAGE_SCHEME};
+use crate::api::local_mods::is_mod_file;
+use crate::api::mods_watcher::{watch_dir, watch_mods_dir};
+use crate::api::settings::{config_dir, AppSettings, AppTheme};
+use crate::api::ui_mod::{UiAuthor, UiMod};
 use crate::components::sidebar::Sidebar;
 use crate::components::mod_info::ModInfoDialog;
 use crate::components::api_dialog::ApiDialog;
+use crate::components::instances_dialog::InstancesDialog;
+use crate::components::detect_dialog::DetectFolderDialog;
+use crate::components::install_dialog::InstallFileDialog;
+use crate::components::deep_link_dialog::DeepLinkDialog;
+use crate::components::recovery_dialog::RecoveryDialog;
+use crate::components::author_dialog::AuthorDialog;
+use crate::components::offline_banner::OfflineBanner;
 use crate::pages::search::SearchPage;
 use crate::pages::installed::InstalledPage;
+use crate::pages::cache::CachePage;
+use crate::pages::following::FollowingPage;
+use crate::state::connectivity::Connectivity;
 use crate::state::mod_store::ModStore;
+use crate::single_instance::Startup;
 
 #[derive(Clone, PartialEq)]
 pub enum ActiveModal {
     None,
     ApiKey,
+    Instances,
+    DetectedFolders(Vec<PathBuf>),
+    InstallFile(Option<PathBuf>),
+    DeepLink(String),
     ModInfo(UiMod),
+    Author(UiAuthor),
 }
 
+/// Launch arguments (deep links, mod files) waiting to be handled, one batch per launch: this process's
+/// own command line, later launches forwarded over IPC, and links the OS hands over while running.
+#[derive(Clone)]
+struct LaunchInbox(Arc<Mutex<Option<UnboundedReceiver<Vec<String>>>>>);
+
 #[derive(Clone, PartialEq)]
 pub enum SidebarTab {
     SearchMods,
     Installed,
+    Following,
+    Cache,
 }
 
 
 
 fn main() {
+    if let Some(code) = cli::run_from_args() {
+        std::process::exit(code);
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let instance = match single_instance::acquire(&args) {
+        Ok(Startup::Primary(guard)) => guard,
+        Ok(Startup::Forwarded) => return,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    url_scheme::register();
+
+    let (launch_sender, launch_receiver) = unbounded_channel::<Vec<String>>();
+    instance.serve(launch_sender.clone());
+    if !args.is_empty() {
+        let _ = launch_sender.send(args);
+    }
+
     let window = WindowBuilder::new()
         .with_title("My App Name")
         .with_resizable(true);
 
+    // Safe to clear on close: the single-instance lock guarantees no other window is using it.
     let dir = std::env::temp_dir().join("com.hytale.modmanager.desktop.cache");
 
     let config = Config::new()
@@ -48,8 +110,16 @@ fn main() {
             move |event, _target| {
                 use dioxus::desktop::tao::event::{Event, WindowEvent};
 
+                // macOS hands over links through an open event rather than the command line.
+                if let Event::Opened { urls } = event {
+                    let _ = launch_sender.send(urls.iter().map(|u| u.to_string()).collect());
+                }
+
                 if let Event::WindowEvent { event, .. } = event {
                     if let WindowEvent::CloseRequested = event {
+                        // Settings saves are batched; write out whatever is still queued.
+                        crate::api::persist::flush();
+
                         println!("Cleaning cache...");
                         match std::fs::remove_dir_all(&dir) {
                             Ok(_) => println!("Cache removed"),
@@ -59,31 +129,167 @@ fn main() {
                 }
             }
         })
+        .with_asynchronous_custom_protocol(IMAGE_SCHEME, |_webview, request, responder| {
+            let path = request.uri().path().to_string();
+            tokio::spawn(async move {
+                let response = match image_cache::serve(&path).await {
+                    Ok((data, mime)) => Response::builder()
+                        .header(CONTENT_TYPE, mime)
+                        .body(Cow::Owned(data)),
+                    Err(e) => {
+                        eprintln!("[Images] {}", e);
+                        Response::builder().status(404).body(Cow::Borrowed(&[][..]))
+                    }
+                };
+                match response {
+                    Ok(response) => responder.respond(response),
+                    Err(e) => eprintln!("[Images] Could not build response: {}", e),
+                }
+            });
+        })
         .with_disable_context_menu(false);
 
     LaunchBuilder::desktop()
         .with_cfg(config)
+        .with_context(LaunchInbox(Arc::new(Mutex::new(Some(launch_receiver)))))
         .launch(App);
 }
 
+/// Takes over changes another process (e.g. the CLI) wrote to settings.json. Our own pending changes are
+/// merged into the file first, so nothing made in the window is lost.
+fn reload_external_changes(mut settings_store: Signal<AppSettings>) {
+    if settings_store.peek().recovery().is_some() || !AppSettings::changed_on_disk() {
+        return;
+    }
+    let reloaded = AppSettings::load();
+    if reloaded.recovery().is_none() {
+        println!("Settings were changed by another process; reloaded them");
+        settings_store.set(reloaded);
+    }
+}
+
 fn App() -> Element {
-    let settings_store = use_signal(|| AppSettings::load());
-    let mod_store = use_signal(|| ModStore::new());
+    let mut settings_store = use_signal(|| AppSettings::load());
+    let mut mod_store = use_signal(|| ModStore::new());
 
+    let mut connectivity = use_context_provider(Connectivity::new);
     use_context_provider(|| mod_store);
     use_context_provider(|| settings_store);
 
+    let api_provider = use_memo(move || settings_store.read().api_provider.clone());
+
+    // Checks more often while offline so the app comes back soon after the connection does.
+    use_resource(move || async move {
+        let _ = (connectivity.check_trigger)();
+        let provider = api_provider();
+        loop {
+            let online = is_reachable(&provider).await;
+            if online != *connectivity.online.peek() {
+                println!("{} is {}", provider.label(), if online { "reachable again" } else { "unreachable, switching to offline mode" });
+                connectivity.online.set(online);
+            }
+            tokio::time::sleep(Duration::from_secs(if online { 60 } else { 15 })).await;
+        }
+    });
+
+    let mods_dir = use_memo(move || settings_store.read().get_mods_dir());
+
+    use_effect(move || {
+        let _ = mods_dir();
+        let mut store = mod_store.write();
+        store.invalidate();
+        store.trigger_refresh();
+    });
+
+    use_resource(move || async move {
+        let Some(mods_dir) = mods_dir() else { return };
+        if let Err(e) = std::fs::create_dir_all(&mods_dir) {
+            println!("Failed to create mods folder: {}", e);
+            return;
+        }
+
+        let (_watcher, mut changes) = match watch_mods_dir(&mods_dir) {
+            Ok(w) => w,
+            Err(e) => {
+                println!("Failed to watch mods folder: {}", e);
+                return;
+            }
+        };
+
+        while changes.recv().await.is_some() {
+            // Coalesce the burst of events a single copy or delete produces.
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            while changes.try_recv().is_ok() {}
+
+            // A CLI install writes its manifest entry right after the file; pick it up before adopting the file.
+            reload_external_changes(settings_store);
+            {
+                let mut settings = settings_store.write();
+                settings.adopt_untracked_files();
+                settings.prune_manifest();
+            }
+
+            let mut store = mod_store.write();
+            store.invalidate();
+            store.trigger_refresh();
+        }
+    });
+
+    // The CLI can change settings.json while the window is open.
+    use_future(move || async move {
+        let (_watcher, mut changes) = match watch_dir(&config_dir()) {
+            Ok(w) => w,
+            Err(e) => {
+                println!("Failed to watch the settings folder: {}", e);
+                return;
+            }
+        };
+        while changes.recv().await.is_some() {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            while changes.try_recv().is_ok() {}
+            reload_external_changes(settings_store);
+        }
+    });
+
     let active_tab = use_signal(|| SidebarTab::SearchMods);
-    let mut active_modal = use_signal(|| ActiveModal::None);
+    let mut active_modal = use_signal(|| {
+        if settings_store.peek().get_game_folder().is_some() {
+            return ActiveModal::None;
+        }
+        let found = detect_installations();
+        if found.is_empty() { ActiveModal::None } else { ActiveModal::DetectedFolders(found) }
+    });
+
+    let launch_inbox = use_context::<LaunchInbox>();
+    let desktop = dioxus::desktop::use_window();
+    use_future(move || {
+        let inbox = launch_inbox.clone();
+        let desktop = desktop.clone();
+        async move {
+            let Some(mut launches) = inbox.0.lock().unwrap().take() else { return };
+            while let Some(args) = launches.recv().await {
+                desktop.window.set_minimized(false);
+                desktop.window.set_focus();
+
+                for arg in args {
+                    if is_deep_link(&arg) {
+                        active_modal.set(ActiveModal::DeepLink(arg));
+                    } else if is_mod_file(&PathBuf::from(&arg)) {
+                        active_modal.set(ActiveModal::InstallFile(Some(PathBuf::from(arg))));
+                    }
+                }
+            }
+        }
+    });
+
 
+    use_effect(move || image_cache::set_limit_mb(settings_store.read().get_image_cache_limit_mb()));
 
     use_effect(move || {
         let config = settings_store;
 
         if let Err(e) = config().save() {
             println!("Failed to save settings: {}", e);
-        } else {
-            println!("Settings saved.");
         }
     });
 
@@ -98,25 +304,49 @@ fn App() -> Element {
         div {
             class: "{theme_class}",
             style: "display: flex; height: 100vh; width: 100vw;",
+            ondragover: |e| e.prevent_default(),
+            ondrop: move |e| {
+                e.prevent_default();
+                if let Some(file) = e.data_transfer().files().first() {
+                    active_modal.set(ActiveModal::InstallFile(Some(file.path())));
+                }
+            },
 
             Sidebar {
                 active_tab: active_tab,
                 on_open_api: move |_| active_modal.set(ActiveModal::ApiKey),
+                on_open_instances: move |_| active_modal.set(ActiveModal::Instances),
             }
 
             div {
                 style: "flex: 1; background-color: var(--bg-secondary); padding: 10px; overflow-y: auto;",
 
+                if !connectivity.is_online() {
+                    OfflineBanner { on_retry: move |_| connectivity.recheck() }
+                }
+
                 match active_tab() {
                     SidebarTab::SearchMods => rsx! {
                         SearchPage {
-                            on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m))
+                            on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m)),
+                            on_open_author: move |a: UiAuthor| active_modal.set(ActiveModal::Author(a))
                         }
                     },
                     SidebarTab::Installed => rsx! {
                         InstalledPage {
-                            on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m))
+                            on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m)),
+                            on_open_author: move |a: UiAuthor| active_modal.set(ActiveModal::Author(a)),
+                            on_install_file: move |path: Option<PathBuf>| active_modal.set(ActiveModal::InstallFile(path))
+                        }
+                    },
+                    SidebarTab::Following => rsx! {
+                        FollowingPage {
+                            on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m)),
+                            on_open_author: move |a: UiAuthor| active_modal.set(ActiveModal::Author(a))
                         }
+                    },
+                    SidebarTab::Cache => rsx! {
+                        CachePage {}
                     }
                 }
             }
@@ -125,14 +355,46 @@ fn App() -> Element {
                 ActiveModal::ApiKey => rsx! {
                     ApiDialog { on_close: move |_| active_modal.set(ActiveModal::None) }
                 },
+                ActiveModal::Instances => rsx! {
+                    InstancesDialog { on_close: move |_| active_modal.set(ActiveModal::None) }
+                },
+                ActiveModal::DetectedFolders(candidates) => rsx! {
+                    DetectFolderDialog {
+                        candidates: candidates,
+                        on_close: move |_| active_modal.set(ActiveModal::None)
+                    }
+                },
+                ActiveModal::DeepLink(link) => rsx! {
+                    DeepLinkDialog {
+                        link: link,
+                        on_close: move |_| active_modal.set(ActiveModal::None)
+                    }
+                },
+                ActiveModal::InstallFile(path) => rsx! {
+                    InstallFileDialog {
+                        initial_path: path,
+                        on_close: move |_| active_modal.set(ActiveModal::None)
+                    }
+                },
                 ActiveModal::ModInfo(m) => rsx! {
                     ModInfoDialog { 
                         mod_data: m, 
-                        on_close: move |_| active_modal.set(ActiveModal::None) 
+                        on_close: move |_| active_modal.set(ActiveModal::None),
+                        on_open_author: move |a: UiAuthor| active_modal.set(ActiveModal::Author(a))
+                    }
+                },
+                ActiveModal::Author(author) => rsx! {
+                    AuthorDialog {
+                        author: author,
+                        on_open_info: move |m: UiMod| active_modal.set(ActiveModal::ModInfo(m)),
+                        on_open_author: move |a: UiAuthor| active_modal.set(ActiveModal::Author(a)),
+                        on_close: move |_| active_modal.set(ActiveModal::None)
                     }
                 },
                 ActiveModal::None => rsx! {}
             }
+
+            RecoveryDialog {}
         }
     }
 }
\ No newline at end of file
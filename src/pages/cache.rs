@@ -0,0 +1,173 @@
+use crate::api::disk_cache::{CacheEntry, DiskCache};
+use crate::api::image_cache;
+use crate::api::settings::AppSettings;
+use dioxus::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn format_size(bytes: u64) -> String {
+    let mb = bytes as f64 / (1024.0 * 1024.0);
+    if mb >= 1024.0 {
+        format!("{:.2} GB", mb / 1024.0)
+    } else {
+        format!("{:.1} MB", mb)
+    }
+}
+
+fn format_last_used(timestamp: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let elapsed = now.saturating_sub(timestamp);
+
+    match elapsed {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", elapsed / 60),
+        3600..=86399 => format!("{} h ago", elapsed / 3600),
+        _ => format!("{} days ago", elapsed / 86400),
+    }
+}
+
+#[component]
+pub fn CachePage() -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+
+    let mut refresh_trigger = use_signal(|| 0);
+    let mut entries = use_signal(Vec::<CacheEntry>::new);
+    let mut error_msg = use_signal(|| Option::<String>::None);
+    let mut limit_input = use_signal(|| app_settings.peek().get_download_cache_limit_mb().to_string());
+    let mut image_limit_input = use_signal(|| app_settings.peek().get_image_cache_limit_mb().to_string());
+    let mut images_used = use_signal(|| 0u64);
+
+    use_effect(move || {
+        let _ = refresh_trigger();
+        let cache = DiskCache::downloads(app_settings.read().get_download_cache_limit_mb());
+        entries.set(cache.entries());
+        images_used.set(DiskCache::images(app_settings.read().get_image_cache_limit_mb()).total_size());
+    });
+
+    let limit_mb = app_settings.read().get_download_cache_limit_mb();
+    let used: u64 = entries.read().iter().map(|e| e.size).sum();
+    let usage_percent = if limit_mb == 0 { 0.0 } else { (used as f64 / (limit_mb as f64 * 1024.0 * 1024.0) * 100.0).min(100.0) };
+    let cache_dir = DiskCache::downloads(limit_mb).dir().display().to_string();
+
+    let apply_limit = move |_| {
+        match limit_input().trim().parse::<u64>() {
+            Ok(limit) => {
+                app_settings.write().set_download_cache_limit_mb(limit);
+                DiskCache::downloads(limit).enforce_limit();
+                error_msg.set(None);
+                refresh_trigger += 1;
+            }
+            Err(_) => error_msg.set(Some("Limit must be a whole number of MB".to_string())),
+        }
+    };
+
+    let image_limit_mb = app_settings.read().get_image_cache_limit_mb();
+
+    let apply_image_limit = move |_| {
+        match image_limit_input().trim().parse::<u64>() {
+            Ok(limit) => {
+                app_settings.write().set_image_cache_limit_mb(limit);
+                image_cache::set_limit_mb(limit);
+                DiskCache::images(limit).enforce_limit();
+                error_msg.set(None);
+                refresh_trigger += 1;
+            }
+            Err(_) => error_msg.set(Some("Limit must be a whole number of MB".to_string())),
+        }
+    };
+
+    let clear_images = move |_| {
+        if let Err(e) = DiskCache::images(image_limit_mb).clear() {
+            error_msg.set(Some(format!("Failed to clear image cache: {}", e)));
+        }
+        refresh_trigger += 1;
+    };
+
+    let clear_cache = move |_| {
+        if let Err(e) = DiskCache::downloads(limit_mb).clear() {
+            error_msg.set(Some(format!("Failed to clear cache: {}", e)));
+        }
+        refresh_trigger += 1;
+    };
+
+    rsx! {
+        div { style: "display: flex; flex-direction: column; height: 100%;",
+            h2 { style: "color: var(--text-primary); margin-top: 0;", "Download Cache" }
+
+            div { style: "display: flex; flex-direction: column; gap: 10px; background-color: var(--bg-tertiary); padding: 15px; border-radius: 8px; margin-bottom: 20px;",
+                div { style: "display: flex; justify-content: space-between; color: var(--text-primary); font-size: 14px;",
+                    span { "Disk usage: {format_size(used)} of {limit_mb} MB" }
+                    span { style: "color: var(--text-secondary);", "{entries.read().len()} files" }
+                }
+                div { style: "height: 8px; background-color: var(--bg-secondary); border-radius: 4px; overflow: hidden;",
+                    div { style: "height: 100%; width: {usage_percent}%; background-color: var(--brand-primary);" }
+                }
+                div { style: "display: flex; gap: 10px; align-items: center;",
+                    label { style: "font-size: 12px; color: var(--text-secondary);", "Size limit (MB)" }
+                    input {
+                        style: "width: 100px;",
+                        value: "{limit_input}",
+                        oninput: move |e| limit_input.set(e.value()),
+                    }
+                    button { class: "btn btn-secondary", onclick: apply_limit, "Apply" }
+                    div { style: "flex: 1;" }
+                    button { class: "btn btn-danger", onclick: clear_cache, "Clear Cache" }
+                }
+                if let Some(err) = error_msg() {
+                    span { style: "font-size: 12px; color: var(--danger);", "{err}" }
+                }
+            }
+
+            div { style: "display: flex; gap: 10px; align-items: center; background-color: var(--bg-tertiary); padding: 15px; border-radius: 8px; margin-bottom: 20px;",
+                span { style: "color: var(--text-primary); font-size: 14px;", "Images: {format_size(images_used())} of {image_limit_mb} MB" }
+                div { style: "flex: 1;" }
+                label { style: "font-size: 12px; color: var(--text-secondary);", "Size limit (MB)" }
+                input {
+                    style: "width: 100px;",
+                    value: "{image_limit_input}",
+                    oninput: move |e| image_limit_input.set(e.value()),
+                }
+                button { class: "btn btn-secondary", onclick: apply_image_limit, "Apply" }
+                button { class: "btn btn-danger", onclick: clear_images, "Clear Images" }
+            }
+
+            div { style: "flex: 1; overflow-y: auto; padding-right: 5px; margin-bottom: 10px;",
+                if entries.read().is_empty() {
+                    div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "The cache is empty." }
+                } else {
+                    div { style: "display: flex; flex-direction: column; gap: 8px;",
+                        for entry in entries.read().iter().cloned() {
+                            div {
+                                key: "{entry.key}",
+                                style: "display: flex; align-items: center; background-color: var(--bg-tertiary); padding: 10px; border-radius: 6px; gap: 15px;",
+                                div { style: "flex: 1; display: flex; flex-direction: column;",
+                                    span { style: "color: var(--text-primary); font-weight: bold;", "{entry.file_name}" }
+                                    div { style: "font-size: 12px; color: var(--text-secondary); display: flex; gap: 10px;",
+                                        span { "{format_size(entry.size)}" }
+                                        span { "•" }
+                                        span { "Used {format_last_used(entry.last_access)}" }
+                                    }
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    style: "padding: 5px 15px; font-size: 12px;",
+                                    onclick: move |_| {
+                                        DiskCache::downloads(limit_mb).remove(&entry.key);
+                                        refresh_trigger += 1;
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { style: "padding: 10px; background-color: var(--bg-tertiary); border-radius: 8px; font-size: 12px; color: var(--text-secondary);",
+                "Location: {cache_dir}"
+            }
+        }
+    }
+}
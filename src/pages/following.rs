@@ -0,0 +1,118 @@
+use dioxus::prelude::*;
+use crate::api::settings::AppSettings;
+use crate::api::ui_mod::{get_updates_feed_unified, UiAuthor, UiMod};
+use crate::components::mod_card::ModCard;
+use crate::state::connectivity::{Connectivity, OFFLINE_REASON};
+
+/// Recent releases from the authors the user follows on the active provider.
+#[component]
+pub fn FollowingPage(on_open_info: EventHandler<UiMod>, on_open_author: EventHandler<UiAuthor>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let connectivity = use_context::<Connectivity>();
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let provider = app_settings.read().api_provider.clone();
+    let followed: Vec<_> = app_settings.read().followed_authors().iter()
+        .filter(|a| a.provider == provider)
+        .cloned()
+        .collect();
+    let followed_ids: Vec<String> = followed.iter().map(|a| a.id.clone()).collect();
+
+    // Reloads when an author is followed or unfollowed, not when one is marked seen.
+    let feed = use_resource(use_reactive((&provider, &followed_ids), move |(provider, _)| async move {
+        let _ = refresh_trigger();
+        if !connectivity.is_online() {
+            return (Vec::new(), Vec::new());
+        }
+        let followed = app_settings.peek().followed_authors().to_vec();
+        get_updates_feed_unified(&provider, &followed).await
+    }));
+
+    // Whether an item is new depends on the live seen marks, so "Mark All Seen" takes effect at once.
+    let is_new = move |author_id: &str, upload_date: &str| {
+        app_settings.read().followed_authors().iter()
+            .find(|a| a.provider == app_settings.read().api_provider && a.id == author_id)
+            .is_some_and(|a| upload_date > a.seen_until.as_str())
+    };
+
+    let mark_all_seen = move |_| {
+        let Some((items, _)) = &*feed.read() else { return };
+        let provider = app_settings.read().api_provider.clone();
+        let mut settings = app_settings.write();
+        for item in items {
+            settings.mark_author_seen(&provider, &item.author.id, &item.mod_data.version.upload_date);
+        }
+    };
+
+    rsx! {
+        div { style: "display: flex; flex-direction: column; height: 100%;",
+            div { style: "display: flex; align-items: center; gap: 10px;",
+                h2 { style: "color: var(--text-primary); margin: 0; flex: 1;", "Following" }
+                button { class: "btn btn-neutral", onclick: move |_| refresh_trigger += 1, "⟳ Refresh" }
+                button { class: "btn btn-brand", disabled: followed.is_empty(), onclick: mark_all_seen, "Mark All Seen" }
+            }
+
+            if followed.is_empty() {
+                div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;",
+                    "You are not following anyone on {provider.label()}. Click an author's name on a mod to open their page and follow them."
+                }
+            } else {
+                div { style: "display: flex; flex-wrap: wrap; gap: 8px; margin: 15px 0;",
+                    for author in followed.iter().cloned() {
+                        div {
+                            key: "{author.id}",
+                            style: "display: flex; align-items: center; gap: 6px; background-color: var(--bg-tertiary); padding: 4px 10px; border-radius: 12px; font-size: 13px; color: var(--text-primary);",
+                            span {
+                                class: "author-link",
+                                onclick: {
+                                    let author = UiAuthor::from(&author);
+                                    move |_| on_open_author.call(author.clone())
+                                },
+                                "{author.name}"
+                            }
+                            span {
+                                style: "cursor: pointer; color: var(--text-secondary);",
+                                title: "Unfollow",
+                                onclick: {
+                                    let provider = provider.clone();
+                                    move |_| app_settings.write().unfollow_author(&provider, &author.id)
+                                },
+                                "✕"
+                            }
+                        }
+                    }
+                }
+
+                div { style: "flex: 1; display: flex; flex-direction: column; gap: 10px;",
+                    match &*feed.read() {
+                        _ if !connectivity.is_online() => rsx! {
+                            div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "{OFFLINE_REASON}" }
+                        },
+                        None => rsx! {
+                            div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "Loading updates..." }
+                        },
+                        Some((items, errors)) => rsx! {
+                            for err in errors.iter() {
+                                span { style: "font-size: 12px; color: var(--danger);", "Could not load {err}" }
+                            }
+                            if items.is_empty() && errors.is_empty() {
+                                div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "No projects yet." }
+                            }
+                            for item in items.iter().cloned() {
+                                div { key: "{item.mod_data.id}", style: "display: flex; flex-direction: column; gap: 4px;",
+                                    div { style: "display: flex; gap: 8px; align-items: center; font-size: 11px; color: var(--text-secondary);",
+                                        if is_new(&item.author.id, &item.mod_data.version.upload_date) {
+                                            span { style: "color: white; background: var(--brand-primary); padding: 1px 6px; border-radius: 4px;", "NEW" }
+                                        }
+                                        span { "{item.mod_data.version.display_name} · {item.mod_data.version.upload_date}" }
+                                    }
+                                    ModCard { mod_data: item.mod_data, onclick: on_open_info, on_open_author }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
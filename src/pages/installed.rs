@@ -1,35 +1,46 @@
 use crate::api::ui_mod::get_mod_details_unified;
-use crate::api::local_mods::extract_base_name;
-use crate::api::settings::AppSettings;
-use crate::api::ui_mod::{UiMod, UiModVersion};
+use crate::api::conflicts::{scan_conflicts, ModConflict};
+use crate::api::local_mods::{extract_base_name, is_mod_file};
+use crate::api::settings::{AppSettings, ContentType, InstalledModEntry};
+use crate::api::ui_mod::{ModMetadata, UiAuthor, UiMod, UiModVersion};
+use crate::components::conflicts_panel::ConflictsPanel;
 use crate::components::mod_card::ModCard;
+use crate::state::connectivity::Connectivity;
 use crate::state::mod_store::ModStore;
 use dioxus::prelude::*;
 use std::fs;
-use std::path::Path;
+use std::path::PathBuf;
 
 #[component]
-pub fn InstalledPage(on_open_info: EventHandler<UiMod>) -> Element {
-    let app_settings = use_context::<Signal<AppSettings>>();
+pub fn InstalledPage(on_open_info: EventHandler<UiMod>, on_open_author: EventHandler<UiAuthor>, on_install_file: EventHandler<Option<PathBuf>>) -> Element {
+    let mut app_settings = use_context::<Signal<AppSettings>>();
+    let mod_store = use_context::<Signal<ModStore>>();
+    let connectivity = use_context::<Connectivity>();
 
     let mut refresh_trigger = use_signal(|| 0);
     let mut query = use_signal(|| String::new());
     let mut display_list = use_signal(|| Vec::<UiMod>::new());
     let mut is_scanning = use_signal(|| false);
+    let mut conflicts = use_signal(Vec::<ModConflict>::new);
+    let mut active_tab = use_signal(|| ContentType::Plugin);
 
     use_resource(move || async move {
         let _ = refresh_trigger();
+        let _ = (mod_store().refresh_trigger)();
+        // Rescans when the connection comes back, picking up fresh details.
+        let online = connectivity.is_online();
         is_scanning.set(true);
 
-        let (folder_opt, installed_map) = {
+        // Details are stored on the instance that was scanned, even if another is selected meanwhile.
+        let (instance, mods_dir_opt, installed_map) = {
             let s = app_settings.peek();
-            (s.get_game_folder(), s.installed_mods.clone())
+            (s.active_instance, s.get_mods_dir(), s.installed_mods().clone())
         };
 
         let mut new_list: Vec<UiMod> = Vec::new();
+        let mut fetched: Vec<(String, ModMetadata)> = Vec::new();
 
-        if let Some(folder) = folder_opt {
-            let mods_path = Path::new(&folder).join("UserData").join("Mods");
+        if let Some(mods_path) = mods_dir_opt {
 
             if !mods_path.exists() {
                 let _ = fs::create_dir_all(&mods_path);
@@ -38,69 +49,50 @@ pub fn InstalledPage(on_open_info: EventHandler<UiMod>) -> Element {
             if let Ok(entries) = fs::read_dir(&mods_path) {
                 for entry in entries.flatten() {
                     let path = entry.path();
-                    if path.is_file() && path.extension().map_or(false, |ext| ext == "jar" || ext == "zip") {
+                    if is_mod_file(&path) {
                         let filename = entry.file_name().to_string_lossy().into_owned();
-
-                        if let Some(known_mod) = installed_map.get(&filename) {
-                            if let Some(ui_mod) = get_mod_details_unified(&known_mod.api_provider, &known_mod.mod_id).await {
-                                new_list.push(ui_mod);
-                                continue;
-                            }
-                        }
-
-                        let (base_name, local_version) = extract_base_name(&filename);
-
-                        let (id, display_name) = if let Some(known) = installed_map.get(&filename) {
-                            (known.mod_id.clone(), known.mod_name.clone())
-                        } else {
-                            ("0".to_string(), base_name.replace("-", " "))
-                        };
-
-                        new_list.push(UiMod {
-                            id,
-                            name: display_name,
-                            summary: format!("Local file: {}", filename),
-                            authors: "Local Install".to_string(),
-                            download_count: 0,
-                            icon: String::new(),
-                            categories: vec![],
-                            version: UiModVersion {
-                                file_id: "0".to_string(),
-                                file_name: filename.clone(),
-                                display_name: local_version,
-                                download_url: None,
-                                release_type: 1,
-                                upload_date: "Local".to_string(),
-                                game_versions: vec![],
-                            },
-                            gallery_urls: vec![],
-                            website_url: String::new(),
-                            banner: String::new(),
-                        });
+                        let (card, fresh) = installed_card(&filename, installed_map.get(&filename), online).await;
+                        new_list.push(card);
+                        fetched.extend(fresh.map(|m| (filename, m)));
                     }
                 }
             }
         }
 
+        // Worlds and prefabs live outside the mods folder, so only the ones we installed are listed.
+        for (filename, known) in installed_map.iter().filter(|(_, e)| e.content_type.is_extracted()) {
+            let (card, fresh) = installed_card(filename, Some(known), online).await;
+            new_list.push(card);
+            fetched.extend(fresh.map(|m| (filename.clone(), m)));
+        }
+
+        if !fetched.is_empty() {
+            let mut settings = app_settings.write();
+            for (filename, metadata) in fetched {
+                settings.update_metadata(instance, &filename, metadata);
+            }
+        }
+
         new_list.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
 
         display_list.set(new_list);
+        conflicts.set(scan_conflicts(&app_settings.peek()));
         is_scanning.set(false);
     });
 
     let filtered_mods = use_memo(move || {
         let mods = display_list.read();
         let q = query().to_lowercase();
-        if q.is_empty() {
-            mods.clone()
-        } else {
-            mods.iter()
-                .filter(|m| m.name.to_lowercase().contains(&q) || m.version.file_name.to_lowercase().contains(&q))
-                .cloned()
-                .collect()
-        }
+        let tab = active_tab();
+        mods.iter()
+            .filter(|m| m.content_type == tab)
+            .filter(|m| q.is_empty() || m.name.to_lowercase().contains(&q) || m.version.file_name.to_lowercase().contains(&q))
+            .cloned()
+            .collect::<Vec<UiMod>>()
     });
 
+    let tab_count = move |content_type: ContentType| display_list.read().iter().filter(|m| m.content_type == content_type).count();
+
     rsx! {
         div { style: "display: flex; flex-direction: column; height: 100%;",
             h2 { style: "color: var(--text-primary); margin-top: 0;", "Installed Mods" }
@@ -116,6 +108,44 @@ pub fn InstalledPage(on_open_info: EventHandler<UiMod>) -> Element {
                     onclick: move |_| refresh_trigger += 1,
                     "Refresh"
                 }
+                button {
+                    class: "btn btn-secondary",
+                    onclick: move |_| {
+                        spawn(async move {
+                            if let Some(file) = rfd::AsyncFileDialog::new()
+                                .add_filter("Hytale mods", &["jar", "zip"])
+                                .pick_file()
+                                .await
+                            {
+                                on_install_file.call(Some(file.path().to_owned()));
+                            }
+                        });
+                    },
+                    "Install from File..."
+                }
+                button {
+                    class: "btn btn-secondary",
+                    onclick: move |_| on_install_file.call(None),
+                    "Install from URL..."
+                }
+            }
+
+            div { style: "display: flex; gap: 20px; margin-bottom: 15px; border-bottom: 1px solid var(--bg-tertiary);",
+                for content_type in ContentType::ALL {
+                    button {
+                        key: "{content_type.label()}",
+                        class: if active_tab() == content_type { "btn btn-tab-active" } else { "btn btn-tab" },
+                        onclick: move |_| active_tab.set(content_type),
+                        "{content_type.label()} ({tab_count(content_type)})"
+                    }
+                }
+            }
+
+            if !conflicts.read().is_empty() {
+                ConflictsPanel {
+                    conflicts: conflicts(),
+                    on_resolved: move |_| refresh_trigger += 1,
+                }
             }
 
             div { style: "flex: 1; overflow-y: auto; padding-right: 5px; margin-bottom: 10px;",
@@ -124,12 +154,12 @@ pub fn InstalledPage(on_open_info: EventHandler<UiMod>) -> Element {
                          div { class: "spinner" }
                          span { "Scanning Mods..." }
                      }
-                } else if display_list.read().is_empty() {
+                } else if filtered_mods.read().is_empty() {
                     div { style: "display: flex; flex-direction: column; align-items: center; justify-content: center; height: 50%; color: var(--text-secondary); gap: 10px;",
                         if app_settings.read().get_game_folder().is_none() {
                              span { style: "color: var(--danger);", "⚠ No Game Folder Set" }
                         } else {
-                            span { "No mods found in folder." }
+                            span { "Nothing installed here yet." }
                         }
                     }
                 } else {
@@ -138,15 +168,70 @@ pub fn InstalledPage(on_open_info: EventHandler<UiMod>) -> Element {
                             ModCard {
                                 key: "{m.version.file_name}",
                                 mod_data: m,
-                                onclick: on_open_info
+                                onclick: on_open_info,
+                                on_open_author,
                             }
                         }
                     }
                 }
             }
             div { style: "padding: 10px; background-color: var(--bg-tertiary); border-radius: 8px; font-size: 12px; color: var(--text-secondary);",
-                if let Some(folder) = app_settings.read().get_game_folder() { "Location: {folder.display()}" } else { "No folder selected" }
+                if let Some(folder) = app_settings.read().get_mods_dir() { "Location: {folder.display()}" } else { "No folder selected" }
+            }
+        }
+    }
+}
+/// Card for an installed file: live provider details when it is managed and the provider answers, the
+/// details cached in the manifest when it does not, a local placeholder otherwise. Freshly fetched details
+/// are returned too, to be cached.
+async fn installed_card(filename: &str, known: Option<&InstalledModEntry>, online: bool) -> (UiMod, Option<ModMetadata>) {
+    if let Some(known_mod) = known.filter(|k| k.mod_id != "0") {
+        if online {
+            if let Some(ui_mod) = get_mod_details_unified(&known_mod.api_provider, &known_mod.mod_id).await {
+                let metadata = ModMetadata::from_mod(&ui_mod);
+                return (UiMod { content_type: known_mod.content_type, ..ui_mod }, Some(metadata));
             }
         }
+        if let Some(metadata) = &known_mod.metadata {
+            return (metadata.to_ui_mod(known_mod), None);
+        }
     }
-}
\ No newline at end of file
+
+    let (base_name, local_version) = extract_base_name(filename);
+
+    // A managed mod keeps its recorded file id, so reinstalling it fetches that exact file.
+    let (id, file_id, display_name, content_type) = if let Some(known) = known {
+        (known.mod_id.clone(), known.file_id.clone(), known.mod_name.clone(), known.content_type)
+    } else {
+        ("0".to_string(), "0".to_string(), base_name.replace("-", " "), ContentType::Plugin)
+    };
+
+    let card = UiMod {
+        id,
+        name: display_name,
+        summary: format!("Local file: {}", filename),
+        authors: "Local Install".to_string(),
+        author_list: vec![],
+        download_count: 0,
+        icon: String::new(),
+        categories: vec![],
+        version: UiModVersion {
+            file_id,
+            file_name: filename.to_string(),
+            display_name: local_version,
+            download_url: None,
+            release_type: 1,
+            upload_date: "Local".to_string(),
+            game_versions: vec![],
+            sha1: None,
+            file_size: None,
+            dependencies: vec![],
+        },
+        gallery_urls: vec![],
+        website_url: String::new(),
+        banner: String::new(),
+        content_type,
+        allow_distribution: true,
+    };
+    (card, None)
+}
@@ -1,2 +1,4 @@
 pub mod search;
-pub mod installed;
\ No newline at end of file
+pub mod installed;
+pub mod cache;
+pub mod following;
\ No newline at end of file
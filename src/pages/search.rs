@@ -1,47 +1,96 @@
 use dioxus::prelude::*;
-use crate::api::ui_mod::search_mods_unified;
+use crate::api::capabilities::capabilities;
+use crate::api::ui_mod::{get_categories_unified, get_featured_unified, search_mods_unified, SearchFilters};
 use crate::api::local_mods::{check_install_status, InstallStatus};
-use crate::api::ui_mod::UiMod;
+use crate::api::ui_mod::{UiAuthor, UiMod};
 use crate::api::settings::AppSettings;
+use crate::components::category_filter::CategoryFilter;
 use crate::components::drop_down::ThemeDropdown;
 use crate::components::mod_card::ModCard;
+use crate::state::connectivity::{Connectivity, OFFLINE_REASON};
 use crate::state::mod_store::ModStore;
 
-const PAGE_SIZE: u32 = 20;
-
 #[component]
-pub fn SearchPage(on_open_info: EventHandler<UiMod>) -> Element {
+pub fn SearchPage(on_open_info: EventHandler<UiMod>, on_open_author: EventHandler<UiAuthor>) -> Element {
     let mut mod_store = use_context::<Signal<ModStore>>();
     let app_settings = use_context::<Signal<AppSettings>>();
+    let connectivity = use_context::<Connectivity>();
+
+    let provider = use_memo(move || app_settings.read().api_provider.clone());
+    let caps = use_memo(move || capabilities(&provider()));
 
     let mut query = use_signal(|| String::new());
-    let mut search_sort = use_signal(|| 0);
+    let mut search_sort = use_signal(|| caps.peek().default_sort());
+    let mut page_size = use_signal(|| caps.peek().default_page_size());
     let mut page_index = use_signal(|| 0);
     let mut search_trigger = use_signal(|| 0);
+    let mut selected_categories = use_signal(Vec::<String>::new);
+    let mut show_featured = use_signal(|| false);
+    // Every request below waits for the connection, and runs again once it is back.
+    let categories = use_resource(move || async move {
+        if !connectivity.is_online() {
+            return Vec::new();
+        }
+        match get_categories_unified(&provider()).await {
+            Ok(categories) => categories,
+            Err(e) => {
+                eprintln!("Category fetch failed: {}", e);
+                Vec::new()
+            }
+        }
+    });
+
+    // Sort orders and category ids differ between providers, so start over from the new one's defaults.
+    use_effect(move || {
+        let caps = caps();
+        search_sort.set(caps.default_sort());
+        page_size.set(caps.default_page_size());
+        selected_categories.set(Vec::new());
+        show_featured.set(false);
+        page_index.set(0);
+    });
 
     let search_resource = use_resource(move || async move {
         let _ = search_trigger();
+        if !connectivity.is_online() {
+            return Err(OFFLINE_REASON.to_string());
+        }
         let current_idx = page_index();
         let query = query.peek().clone();
         let settings = app_settings.read().clone();
-        let offset = current_idx * PAGE_SIZE;
+        let page_size = page_size();
+        let offset = current_idx * page_size;
         let sort = search_sort();
-        
-        match search_mods_unified(&settings, sort, query, offset).await {
-            Ok((ui_mods, total)) => (ui_mods, total),
-            Err(_) => (vec![], 0)
+        let filters = SearchFilters { categories: selected_categories() };
+
+        search_mods_unified(&settings, sort, query, offset, page_size, &filters).await
+    });
+
+    let featured_resource = use_resource(move || async move {
+        if !show_featured() {
+            return Ok(Vec::new());
+        }
+        if !connectivity.is_online() {
+            return Err(OFFLINE_REASON.to_string());
         }
+        get_featured_unified(&provider()).await
     });
 
-    let (mods, total_pages) = match search_resource.read().as_ref() {
-        Some((m, t)) => (m.clone(), *t),
-        None => (vec![], 0)
+    let (mods, total_pages, search_error) = match search_resource.read().as_ref() {
+        Some(Ok((m, t))) => (m.clone(), *t, None),
+        Some(Err(e)) => (vec![], 0, Some(e.clone())),
+        None => (vec![], 0, None)
     };
+    let sort_labels: Vec<String> = caps().sort_fields.iter().map(|s| s.label().to_string()).collect();
+    let sort_index = caps().sort_fields.iter().position(|s| *s == search_sort());
+    let page_size_labels: Vec<String> = caps().page_sizes.iter().map(|n| format!("{} per page", n)).collect();
+    let page_size_index = caps().page_sizes.iter().position(|n| *n == page_size());
 
     let current_page_display = page_index() + 1;
+    let pager_display = if show_featured() { "none" } else { "flex" };
 
     use_effect(move || {
-        if let Some((mods, _)) = search_resource.read().as_ref() {
+        if let Some(Ok((mods, _))) = search_resource.read().as_ref() {
             let settings = app_settings.read();
 
             for m in mods {
@@ -80,27 +129,95 @@ pub fn SearchPage(on_open_info: EventHandler<UiMod>) -> Element {
 
                 div { style: "flex: 2; min-width: 0;",
                     ThemeDropdown {
-                        items: ["Popularity".to_string(), "Featured".to_string(), "LastUpdated".to_string(), "Rating".to_string(), "Creation Date".to_string(), "Total Downloads".to_string()],
+                        items: sort_labels,
                         upwards: false,
-                        placeholder: "Theme",
-                        default_index: Some(0),
-                        on_select: move |c: usize| search_sort.set(c as u32),
+                        placeholder: "Sort",
+                        default_index: sort_index,
+                        on_select: move |i: usize| {
+                            if let Some(sort) = caps().sort_fields.get(i) {
+                                search_sort.set(*sort);
+                                page_index.set(0);
+                            }
+                        },
+                    }
+                }
+                if caps().category_filter {
+                    div { style: "flex: 2; min-width: 0;",
+                        CategoryFilter {
+                            categories: categories.read().clone().unwrap_or_default(),
+                            selected: selected_categories(),
+                            max: caps().max_categories,
+                            on_change: move |selected| {
+                                selected_categories.set(selected);
+                                page_index.set(0);
+                            },
+                        }
+                    }
+                }
+                if caps().page_sizes.len() > 1 {
+                    div { style: "flex: 1; min-width: 0;",
+                        ThemeDropdown {
+                            items: page_size_labels,
+                            upwards: false,
+                            placeholder: "Page size",
+                            default_index: page_size_index,
+                            on_select: move |i: usize| {
+                                if let Some(size) = caps().page_sizes.get(i) {
+                                    page_size.set(*size);
+                                    page_index.set(0);
+                                }
+                            },
+                        }
+                    }
+                }
+                button { class: "btn btn-brand", onclick: move |_| {
+                    show_featured.set(false);
+                    *search_trigger.write() += 1;
+                }, "Search" }
+                if caps().featured {
+                    button {
+                        class: if show_featured() { "btn btn-brand" } else { "btn btn-ghost" },
+                        onclick: move |_| show_featured.set(!show_featured()),
+                        "Featured"
                     }
                 }
-                button { class: "btn btn-brand", onclick: move |_| *search_trigger.write() += 1, "Search" }
             }
 
             div {
                 style: "flex: 1; overflow-y: auto; padding-right: 5px; margin-bottom: 10px;",
 
-                if search_resource.finished() {
-                    if mods.is_empty() {
+                if !connectivity.is_online() {
+                    div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "{OFFLINE_REASON}" }
+                } else if show_featured() {
+                    match featured_resource.read().as_ref() {
+                        None => rsx! {
+                            div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "Loading featured mods..." }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { style: "text-align: center; color: var(--danger); margin-top: 50px;", "{e}" }
+                        },
+                        Some(Ok(sections)) => rsx! {
+                            for (title, section_mods) in sections.clone() {
+                                h3 { key: "{title}", style: "color: var(--text-primary); margin: 10px 0;", "{title}" }
+                                div {
+                                    style: "display: flex; flex-direction: column; gap: 10px;",
+                                    for m in section_mods {
+                                        ModCard { key: "{title}-{m.id}", mod_data: m, onclick: on_open_info, on_open_author }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                } else if search_resource.finished() {
+                    if let Some(err) = search_error {
+                        div { style: "text-align: center; color: var(--danger); margin-top: 50px;", "{err}" }
+                    } else if mods.is_empty() {
                          div { style: "text-align: center; color: var(--text-secondary); margin-top: 50px;", "No results found." }
                     }
                     div {
                         style: "display: flex; flex-direction: column; gap: 10px;",
                         for m in mods {
-                            ModCard { key: "{m.id}", mod_data: m, onclick: on_open_info }
+                            ModCard { key: "{m.id}", mod_data: m, onclick: on_open_info, on_open_author }
                         }
                     }
                 } else {
@@ -109,7 +226,7 @@ pub fn SearchPage(on_open_info: EventHandler<UiMod>) -> Element {
             }
 
             div {
-                style: "display: flex; justify-content: center; align-items: center; gap: 20px; padding: 5px; background-color: var(--bg-tertiary); border-radius: 8px; border: 1px solid var(--border-color); margin-top: auto;",
+                style: "display: {pager_display}; justify-content: center; align-items: center; gap: 20px; padding: 5px; background-color: var(--bg-tertiary); border-radius: 8px; border: 1px solid var(--border-color); margin-top: auto;",
 
                 button {
                     class: "btn btn-ghost",
@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::hash::{BuildHasher, RandomState};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{self, Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc::UnboundedSender;
+use crate::api::deep_link::is_deep_link;
+use crate::api::settings::config_dir;
+
+/// Holds the single-instance lock for as long as the app runs. The OS releases it when the process exits.
+pub struct InstanceGuard {
+    _lock: Option<File>,
+    listener: TcpListener,
+    token: String,
+}
+
+pub enum Startup {
+    /// This process is the only one running and owns the window.
+    Primary(InstanceGuard),
+    /// Another instance is running and has been handed our arguments.
+    Forwarded,
+}
+
+/// What a second launch sends to the running instance: its arguments (files, deep links).
+#[derive(Serialize, Deserialize)]
+struct ForwardedLaunch {
+    token: String,
+    args: Vec<String>,
+}
+
+fn lock_path() -> PathBuf {
+    config_dir().join("instance.lock")
+}
+
+/// Port and token of the running instance's IPC listener.
+fn endpoint_path() -> PathBuf {
+    config_dir().join("instance.port")
+}
+
+/// Takes the single-instance lock, or forwards `args` to the instance that already holds it.
+pub fn acquire(args: &[String]) -> Result<Startup, String> {
+    fs::create_dir_all(config_dir()).map_err(|e| e.to_string())?;
+
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_path())
+        .map_err(|e| format!("Failed to open lock file: {}", e))?;
+
+    let lock = match lock_file.try_lock() {
+        Ok(()) => Some(lock_file),
+        Err(TryLockError::WouldBlock) => {
+            forward(&resolve_paths(args))?;
+            return Ok(Startup::Forwarded);
+        }
+        Err(TryLockError::Error(e)) => {
+            // Some filesystems do not support locking; run unguarded rather than not at all.
+            eprintln!("Single-instance lock unavailable: {}", e);
+            None
+        }
+    };
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(|e| format!("Failed to open IPC listener: {}", e))?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    let token = new_token();
+    fs::write(endpoint_path(), format!("{} {}", port, token)).map_err(|e| e.to_string())?;
+
+    Ok(Startup::Primary(InstanceGuard { _lock: lock, listener, token }))
+}
+
+impl InstanceGuard {
+    /// Accepts launches forwarded by later instances on a background thread and passes their arguments on.
+    pub fn serve(&self, sender: UnboundedSender<Vec<String>>) {
+        let listener = match self.listener.try_clone() {
+            Ok(l) => l,
+            Err(e) => {
+                eprintln!("Failed to start IPC listener: {}", e);
+                return;
+            }
+        };
+        let token = self.token.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+                let mut line = String::new();
+                if BufReader::new(stream).read_line(&mut line).is_err() {
+                    continue;
+                }
+                match serde_json::from_str::<ForwardedLaunch>(&line) {
+                    Ok(launch) if launch.token == token => {
+                        if sender.send(launch.args).is_err() {
+                            return;
+                        }
+                    }
+                    _ => eprintln!("[IPC] Ignored an invalid message"),
+                }
+            }
+        });
+    }
+}
+
+/// The running instance has its own working directory, so paths relative to ours are made absolute before
+/// they are handed over. Deep links and anything that is not an existing file are passed on unchanged.
+/// `path::absolute` rather than `fs::canonicalize`, which gives `\\?\` paths on Windows.
+fn resolve_paths(args: &[String]) -> Vec<String> {
+    args.iter()
+        .map(|arg| {
+            if is_deep_link(arg) || !Path::new(arg).exists() {
+                return arg.clone();
+            }
+            path::absolute(arg).map(|p| p.to_string_lossy().into_owned()).unwrap_or_else(|_| arg.clone())
+        })
+        .collect()
+}
+
+fn forward(args: &[String]) -> Result<(), String> {
+    let message = |token: String| serde_json::to_string(&ForwardedLaunch { token, args: args.to_vec() });
+
+    // The running instance may still be starting up and not have written its endpoint yet.
+    let mut last_error = String::new();
+    for _ in 0..10 {
+        match read_endpoint().and_then(|(port, token)| {
+            let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+            let mut stream = TcpStream::connect_timeout(&addr, Duration::from_secs(1)).map_err(|e| e.to_string())?;
+            let body = message(token).map_err(|e| e.to_string())?;
+            writeln!(stream, "{}", body).map_err(|e| e.to_string())
+        }) {
+            Ok(()) => return Ok(()),
+            Err(e) => last_error = e,
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    }
+
+    Err(format!("Hytale Mod Manager is already running but did not respond: {}", last_error))
+}
+
+fn read_endpoint() -> Result<(u16, String), String> {
+    let content = fs::read_to_string(endpoint_path()).map_err(|e| e.to_string())?;
+    let (port, token) = content.trim().split_once(' ').ok_or("Malformed endpoint file")?;
+    Ok((port.parse().map_err(|_| "Malformed endpoint file")?, token.to_string()))
+}
+
+fn new_token() -> String {
+    let seed = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+    format!(
+        "{:016x}{:016x}",
+        RandomState::new().hash_one(seed),
+        RandomState::new().hash_one(std::process::id()),
+    )
+}
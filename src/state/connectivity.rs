@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+
+/// Shown on whatever needs the provider while it cannot be reached.
+pub const OFFLINE_REASON: &str = "Unavailable offline: the provider cannot be reached. Installed mods keep working.";
+
+/// Whether the active provider can be reached. Assumed online until the first check says otherwise.
+#[derive(Clone, Copy)]
+pub struct Connectivity {
+    pub online: Signal<bool>,
+    /// Bumped to check again right away instead of waiting for the next periodic check.
+    pub check_trigger: Signal<u32>,
+}
+
+impl Connectivity {
+    pub fn new() -> Self {
+        Self {
+            online: Signal::new(true),
+            check_trigger: Signal::new(0),
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        (self.online)()
+    }
+
+    pub fn recheck(&mut self) {
+        self.check_trigger += 1;
+    }
+}
@@ -1 +1,3 @@
-pub mod mod_store;
\ No newline at end of file
+pub mod mod_store;
+pub mod mod_actions;
+pub mod connectivity;
\ No newline at end of file
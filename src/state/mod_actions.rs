@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+use crate::api::installer::download_version;
+use crate::api::local_mods::remove_mod;
+use crate::api::settings::AppSettings;
+use crate::api::ui_mod::{UiMod, UiModVersion};
+use crate::state::mod_store::ModStore;
+
+/// Downloads and installs `version` of `mod_data` in the background. The mod is marked as processing
+/// until it finishes; failures land in `error_msg`.
+pub fn spawn_install(
+    app_settings: Signal<AppSettings>,
+    mod_store: Signal<ModStore>,
+    error_msg: Signal<Option<String>>,
+    mod_data: UiMod,
+    version: UiModVersion,
+) {
+    spawn_install_all(app_settings, mod_store, error_msg, vec![(mod_data, version)]);
+}
+
+/// Installs several mods one after another, e.g. a mod after its missing dependencies. Stops at the first
+/// failure so a mod is not left installed without something it needs.
+pub fn spawn_install_all(
+    mut app_settings: Signal<AppSettings>,
+    mut mod_store: Signal<ModStore>,
+    mut error_msg: Signal<Option<String>>,
+    installs: Vec<(UiMod, UiModVersion)>,
+) {
+    for (mod_data, _) in &installs {
+        mod_store.write().set_processing(&mod_data.id, true);
+    }
+    error_msg.set(None);
+    let instance = app_settings.read().active_instance;
+
+    spawn(async move {
+        for (mod_data, version) in &installs {
+            if mod_data.needs_manual_download(version) {
+                error_msg.set(Some(format!("{} has to be downloaded manually from its own page", mod_data.name)));
+                break;
+            }
+            let settings = app_settings.read().clone();
+            let result = match download_version(&settings, mod_data, version).await {
+                Ok(downloaded) => downloaded.install(instance, &mut app_settings.write())
+                    .map_err(|e| format!("Install error: {}", e)),
+                Err(e) => Err(format!("Download failed: {}", e)),
+            };
+            if let Err(e) = result {
+                let e = if installs.len() > 1 { format!("{}: {}", mod_data.name, e) } else { e };
+                error_msg.set(Some(e));
+                break;
+            }
+        }
+        for (mod_data, _) in &installs {
+            mod_store.write().set_processing(&mod_data.id, false);
+        }
+    });
+}
+
+pub fn remove_installed(
+    mut app_settings: Signal<AppSettings>,
+    mut error_msg: Signal<Option<String>>,
+    local_file: Option<String>,
+) {
+    error_msg.set(None);
+    match local_file {
+        Some(file) => {
+            if let Err(e) = remove_mod(&file, &mut app_settings.write()) {
+                error_msg.set(Some(e));
+            }
+        }
+        None => error_msg.set(Some("File not found locally".to_string())),
+    }
+}
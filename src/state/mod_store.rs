@@ -60,6 +60,11 @@ impl ModStore {
         }
     }
 
+    /// Drops every cached status, e.g. after the Mods folder changed on disk.
+    pub fn invalidate(&mut self) {
+        self.status_cache.write().clear();
+    }
+
     pub fn trigger_refresh(&mut self) {
         self.refresh_trigger += 1;
     }
@@ -132,6 +132,25 @@ pub const GLOBAL_CSS: &str = r#"
         box-shadow: 0 0 0 2px rgba(5, 150, 105, 0.1);
     }
     
+    .author-link { cursor: pointer; }
+    .author-link:hover { text-decoration: underline; }
+
+    .rich-text {
+        line-height: 1.7;
+        color: var(--text-primary);
+        font-size: 14px;
+        overflow-wrap: anywhere;
+    }
+    .rich-text img { max-width: 100%; height: auto; border-radius: 4px; }
+    .rich-text a { color: var(--brand-primary); }
+    .rich-text h1, .rich-text h2, .rich-text h3 { margin: 1em 0 0.4em; }
+    .rich-text pre, .rich-text code { background: var(--bg-quaternary); border-radius: 4px; font-size: 12px; }
+    .rich-text pre { padding: 10px; overflow-x: auto; }
+    .rich-text code { padding: 1px 4px; }
+    .rich-text blockquote { margin: 0; padding-left: 12px; border-left: 3px solid var(--bg-quaternary); color: var(--text-secondary); }
+    .rich-text table { border-collapse: collapse; }
+    .rich-text td, .rich-text th { border: 1px solid var(--bg-quaternary); padding: 4px 8px; }
+
     ::-webkit-scrollbar { width: 8px; }
     ::-webkit-scrollbar-track { background: transparent; }
     ::-webkit-scrollbar-thumb { background: var(--bg-quaternary); border-radius: 4px; }
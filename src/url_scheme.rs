@@ -0,0 +1,40 @@
+/// Makes the OS send `hytalemm://` links to this executable.
+///
+/// Linux picks the handler up from `MimeType=x-scheme-handler/hytalemm` in the desktop entry and macOS
+/// from `CFBundleURLTypes` in the bundle's Info.plist, so only Windows needs registering at runtime.
+/// The per-user registry keys are rewritten on every start so they follow the exe if it is moved.
+pub fn register() {
+    #[cfg(windows)]
+    {
+        use crate::api::deep_link::SCHEME;
+        use std::os::windows::process::CommandExt;
+        use std::process::Command;
+
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        let Ok(exe) = std::env::current_exe() else { return };
+        let key = format!(r"HKCU\Software\Classes\{}", SCHEME);
+        let command = format!("\"{}\" \"%1\"", exe.display());
+
+        let entries = [
+            (key.clone(), "/ve", "URL:Hytale Mod Manager".to_string()),
+            (key.clone(), "/v", String::new()),
+            (format!(r"{}\shell\open\command", key), "/ve", command),
+        ];
+
+        for (path, value_flag, data) in entries {
+            let mut reg = Command::new("reg");
+            reg.creation_flags(CREATE_NO_WINDOW);
+            reg.args(["add", &path, "/f"]);
+            if value_flag == "/v" {
+                reg.args(["/v", "URL Protocol", "/d", &data]);
+            } else {
+                reg.args(["/ve", "/d", &data]);
+            }
+            if let Err(e) = reg.output() {
+                eprintln!("Failed to register {}:// links: {}", SCHEME, e);
+                return;
+            }
+        }
+    }
+}